@@ -0,0 +1,108 @@
+use std::collections::{BinaryHeap, HashMap};
+use std::cmp::Reverse;
+
+use anyhow::Result;
+use aoc_core::{parse, Diagnostics, Solution};
+
+pub struct Day1;
+
+#[derive(Debug, Clone)]
+pub struct Lists {
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Result<Lists> {
+    parse_with(input, &mut Diagnostics::strict())
+}
+
+/// Every line must hold exactly two numbers; a lenient parse skips the ones that don't.
+pub fn parse_with(input: &str, diagnostics: &mut Diagnostics) -> Result<Lists> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in parse::lines(input) {
+        if let Some([l, r]) = diagnostics.check(line, line.columns::<u32, 2>())? {
+            left.push(l);
+            right.push(r);
+        }
+    }
+
+    Ok(Lists { left, right })
+}
+
+pub fn part1(lists: &Lists) -> u32 {
+    let mut left_heap: BinaryHeap<_> = lists.left.iter().map(|v| Reverse(*v)).collect();
+    let mut right_heap: BinaryHeap<_> = lists.right.iter().map(|v| Reverse(*v)).collect();
+
+    let mut ans = 0;
+    while let (Some(Reverse(left_min)), Some(Reverse(right_min))) =
+        (left_heap.pop(), right_heap.pop())
+    {
+        let diff = right_min.abs_diff(left_min);
+        ans += diff;
+    }
+    ans
+}
+
+pub fn part2(lists: &Lists) -> u32 {
+    let mut freq: HashMap<u32, u32> = lists.left.iter().map(|v| (*v, 0)).collect();
+
+    for right_value in &lists.right {
+        if let Some(count) = freq.get_mut(right_value) {
+            *count += 1;
+        }
+    }
+
+    let mut ans = 0;
+    for value in &lists.left {
+        if let Some((key, count)) = freq.get_key_value(value) {
+            ans += key * count;
+        }
+    }
+    ans
+}
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Parsed = Lists;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Lists> {
+        parse(input)
+    }
+
+    fn parse_with(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Lists> {
+        parse_with(input, diagnostics)
+    }
+
+    fn part1(&self, lists: &Lists) -> Result<u32> {
+        Ok(part1(lists))
+    }
+
+    fn part2(&self, lists: &Lists) -> Result<u32> {
+        Ok(part2(lists))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day1.parse(EXAMPLE).unwrap();
+        assert_eq!(Day1.part1(&parsed).unwrap(), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day1.parse(EXAMPLE).unwrap();
+        assert_eq!(Day1.part2(&parsed).unwrap(), 31);
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use aoc_core::{parse, Diagnostics, Solution};
+
+pub struct Day2;
+
+pub fn is_safe(report: &[i32]) -> bool {
+    if report.len() < 2 {
+        return true
+    }
+
+    let mut increasing = true;
+    let mut decreasing = true;
+
+    for window in report.windows(2) {
+        let diff = window[1] - window[0];
+        if !(1..=3).contains(&diff) {
+            increasing = false;
+        }
+        if !(-3..=-1).contains(&diff) {
+            decreasing = false
+        }
+    }
+
+    increasing || decreasing
+}
+
+pub fn check_with_remove(report: &[i32]) -> bool {
+    for i in 0..report.len() {
+        let mut modified_report = report.to_vec();
+        modified_report.remove(i);
+        if is_safe(&modified_report) {
+            return true;
+        }
+    }
+    false
+}
+
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+    parse_with(input, &mut Diagnostics::strict())
+}
+
+/// One report of whitespace-separated levels per line; a lenient parse skips reports that don't parse.
+pub fn parse_with(input: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Vec<i32>>> {
+    let mut reports = Vec::new();
+    for line in parse::lines(input) {
+        if let Some(report) = diagnostics.check(line, line.numbers())? {
+            reports.push(report);
+        }
+    }
+    Ok(reports)
+}
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i32>>> {
+        parse(input)
+    }
+
+    fn parse_with(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Vec<i32>>> {
+        parse_with(input, diagnostics)
+    }
+
+    fn part1(&self, reports: &Vec<Vec<i32>>) -> Result<usize> {
+        Ok(reports.iter().filter(|report| is_safe(report)).count())
+    }
+
+    fn part2(&self, reports: &Vec<Vec<i32>>) -> Result<usize> {
+        Ok(reports
+            .iter()
+            .filter(|report| is_safe(report) || check_with_remove(report))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day2.parse(EXAMPLE).unwrap();
+        assert_eq!(Day2.part1(&parsed).unwrap(), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day2.parse(EXAMPLE).unwrap();
+        assert_eq!(Day2.part2(&parsed).unwrap(), 4);
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::{anyhow, Result};
+use aoc_core::{AocError, Solution};
+use lazy_regex::regex;
+
+/// `do()`, `don't()` and well-formed `mul(x,y)` instructions, the only ones either part reads.
+pub static INSTRUCTION: &lazy_regex::Lazy<regex::Regex> =
+    regex!(r"(do\(\)|don't\(\)|mul\([1-9][0-9]{0,2},[1-9][0-9]{0,2}\))");
+
+pub struct Day3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    x: u64,
+    y: u64,
+}
+
+impl Instruction {
+    /// The product, or `None` if it doesn't fit in a `u64`.
+    pub fn output(&self) -> Option<u64> {
+        self.x.checked_mul(self.y)
+    }
+
+    /// Reads exactly `mul(X,Y)` where `X` and `Y` are plain decimal numbers.
+    pub fn parse_mul(input: &str) -> Option<Self> {
+        let trimmed = input.strip_prefix("mul(")?.strip_suffix(')')?;
+        let (x, y) = trimmed.split_once(',')?;
+        let number = |digits: &str| {
+            if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse::<u64>().ok()
+        };
+        Some(Self {
+            x: number(x)?,
+            y: number(y)?,
+        })
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = AocError;
+    fn try_from(item: &str) -> Result<Self, AocError> {
+        let instruction = Instruction::parse_mul(item);
+        match instruction {
+            Some(instruction) => Ok(instruction),
+            _ => Err(AocError::invalid(format!("invalid instruction '{item}'")))
+        }
+    }
+}
+
+/// One instruction the scanner recognised in the corrupted memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Do,
+    Dont,
+    Mul(Instruction),
+}
+
+fn add_product(total: u64, instruction: &Instruction) -> Result<u64> {
+    instruction
+        .output()
+        .and_then(|product| total.checked_add(product))
+        .ok_or_else(|| anyhow!("{instruction:?} overflows the running total"))
+}
+
+pub fn part1(ops: &[Op]) -> Result<u64> {
+    ops.iter().try_fold(0, |total, op| match op {
+        Op::Mul(instruction) => add_product(total, instruction),
+        Op::Do | Op::Dont => Ok(total),
+    })
+}
+
+pub fn part2(ops: &[Op]) -> Result<u64> {
+    let mut total = 0;
+    let mut enabled = true;
+    for op in ops {
+        match op {
+            Op::Do => enabled = true,
+            Op::Dont => enabled = false,
+            Op::Mul(instruction) if enabled => total = add_product(total, instruction)?,
+            Op::Mul(_) => {}
+        }
+    }
+    Ok(total)
+}
+
+/// Scans the whole program in place, keeping only the instructions.
+pub fn parse(input: &str) -> Result<Vec<Op>> {
+    INSTRUCTION
+        .find_iter(input)
+        .map(|found| match found.as_str() {
+            "do()" => Ok(Op::Do),
+            "don't()" => Ok(Op::Dont),
+            mul => Ok(Op::Mul(Instruction::try_from(mul)?)),
+        })
+        .collect()
+}
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Op>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Op>> {
+        parse(input)
+    }
+
+    fn part1(&self, ops: &Vec<Op>) -> Result<u64> {
+        part1(ops)
+    }
+
+    fn part2(&self, ops: &Vec<Op>) -> Result<u64> {
+        part2(ops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const EXAMPLE_PART2: &str = include_str!("../fixtures/example_part2.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day3.parse(EXAMPLE).unwrap();
+        assert_eq!(Day3.part1(&parsed).unwrap(), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day3.parse(EXAMPLE_PART2).unwrap();
+        assert_eq!(Day3.part2(&parsed).unwrap(), 48);
+    }
+
+    #[test]
+    fn parse_mul_rejects_short_and_non_ascii_input() {
+        // Each of these used to panic slicing `&input[4..input.len() - 1]`.
+        for input in ["", "mul", "mul(", "m(1)", "mul\u{e9}(1,2)", "\u{1f600}"] {
+            assert!(Instruction::parse_mul(input).is_none(), "{input:?}");
+        }
+        assert!(Instruction::try_from("mul").is_err());
+    }
+
+    #[test]
+    fn parse_mul_rejects_extra_or_signed_fields() {
+        // These used to read as `mul(1,2)` because unparseable fields were dropped.
+        for input in ["mul(1,,2)", "mul(1,2,)", "mul(+1,2)", "mul(,1,2)", "mul(1, 2)"] {
+            assert!(Instruction::parse_mul(input).is_none(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn oversized_products_are_an_error() {
+        let ops = parse("xmul(1,2)do()").unwrap();
+        assert_eq!(part1(&ops).unwrap(), 2);
+        let huge = Instruction::parse_mul(&format!("mul({},2)", u64::MAX)).unwrap();
+        assert_eq!(huge.output(), None);
+        assert!(part1(&[Op::Mul(huge)]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_mul_never_panics(input in "\\PC*") {
+            let _ = Instruction::parse_mul(&input);
+        }
+
+        #[test]
+        fn parse_mul_accepts_exactly_well_formed_near_misses(input in "(mul\\(|m?u?l?\\(?)[0-9,+ -]{0,8}\\)?") {
+            let well_formed = regex!(r"^mul\([0-9]+,[0-9]+\)$").is_match(&input);
+            prop_assert_eq!(Instruction::parse_mul(&input).is_some(), well_formed);
+        }
+
+        #[test]
+        fn parse_mul_reads_well_formed_instructions(x in 0u64..1000, y in 0u64..1000) {
+            let instruction = Instruction::parse_mul(&format!("mul({x},{y})"));
+            prop_assert_eq!(instruction.and_then(|instruction| instruction.output()), Some(x * y));
+        }
+    }
+}
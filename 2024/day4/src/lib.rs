@@ -0,0 +1,84 @@
+use anyhow::Result;
+use aoc_core::{Color, Grid, Part, Pattern, Point, Scene, Solution};
+
+
+pub struct Day4;
+
+/// XMAS written in any direction.
+pub fn xmas() -> Pattern {
+    Pattern::ray("XMAS")
+}
+
+/// Two MAS crossing on their A, each written either way round.
+pub fn x_mas() -> Pattern {
+    Pattern::new("M?S\n?A?\nM?S").rotations()
+}
+
+/// Every cell that is part of an XMAS.
+pub fn xmas_cells(grid: &Grid<char>) -> Vec<Point> {
+    xmas().matches(grid).into_iter().flat_map(|found| found.cells).collect()
+}
+
+/// Every cell that is part of an X-MAS.
+pub fn x_mas_cells(grid: &Grid<char>) -> Vec<Point> {
+    x_mas().matches(grid).into_iter().flat_map(|found| found.cells).collect()
+}
+
+/// The word search with everything but the matched letters faded out.
+pub fn scene(grid: &Grid<char>, part: Part) -> Scene {
+    let (name, cells) = match part {
+        Part::One => ("XMAS", xmas_cells(grid)),
+        Part::Two => ("X-MAS", x_mas_cells(grid)),
+    };
+    Scene::new(grid.clone())
+        .layer(name, Color::Green, None, cells)
+        .fade_unmarked()
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    Ok(input.parse()?)
+}
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Parsed = Grid<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>) -> Result<u64> {
+        Ok(xmas().count(grid) as u64)
+    }
+
+    fn part2(&self, grid: &Grid<char>) -> Result<u64> {
+        Ok(x_mas().count(grid) as u64)
+    }
+
+    fn scene(&self, grid: &Grid<char>, part: Part) -> Result<Option<Scene>> {
+        Ok(Some(scene(grid, part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part1(&parsed).unwrap(), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part2(&parsed).unwrap(), 9);
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, hash_map::Entry};
+
+use anyhow::{bail, Result};
+use aoc_core::{parse, Diagnostics, Solution};
+
+pub struct Day5;
+
+#[derive(Debug, Clone)]
+pub struct PrintQueue {
+    pub task_prereq: HashMap<u64, Vec<u64>>,
+    pub updates: Vec<Vec<u64>>,
+}
+
+pub fn is_valid(updates: &[u64], task_prereq: &HashMap<u64, Vec<u64>>) -> bool {
+    for (i, task) in updates.iter().enumerate() {
+        for order in i+1..updates.len() {
+            // let check_task = updates.get(i).unwrap();
+            let prereq = updates.get(order).unwrap();
+            match task_prereq.contains_key(task) {
+                true => {
+                    if task_prereq.get(task).unwrap().contains(prereq) {
+                        return false
+                    }
+                }
+                false => continue
+            }
+        }
+   } 
+    true
+}
+
+/// Reorders an update to follow the rules; rules that loop between its pages are an error.
+pub fn fix_order(updates: &mut [u64], task_prereq: &HashMap<u64, Vec<u64>>) -> Result<()> {
+    let n = updates.len();
+    let mut i = 0;
+    // Each swap at `i` brings in a page that must come before the last one, so without a
+    // cycle a position settles within `n` rounds.
+    let mut rounds = 0;
+    while i < n {
+        rounds += 1;
+        if rounds > n {
+            bail!("The ordering rules loop between the pages of update {updates:?}");
+        }
+        // let check_task = updates.get(i).unwrap();
+        let mut swapped = false;
+        let task = *updates.get(i).unwrap();
+        for j in i+1..n {
+            let prereq = updates[j];
+            match task_prereq.contains_key(&task) {
+                true => {
+                    if task_prereq.get(&task).unwrap().contains(&prereq) {
+                        updates.swap(i, j);
+                        swapped = true
+                    }
+                }
+                false => continue
+            }
+        }
+        if !swapped {
+           i += 1; 
+           rounds = 0;
+        }
+    }
+    Ok(())
+}
+
+
+pub fn parse(input: &str) -> Result<PrintQueue> {
+    parse_with(input, &mut Diagnostics::strict())
+}
+
+/// Ordering rules, a blank line, then updates; a lenient parse skips rules and updates that don't parse.
+pub fn parse_with(input: &str, diagnostics: &mut Diagnostics) -> Result<PrintQueue> {
+    let mut sections = parse::sections(input).into_iter();
+    let mut task_prereq: HashMap<u64, Vec<u64>> = HashMap::new();
+    let mut updates: Vec<Vec<u64>> = Vec::new();
+    for line in sections.next().unwrap_or_default().lines {
+        let Some((before, after)) = diagnostics.check(line, line.pair::<u64>('|'))? else {
+            continue;
+        };
+        match task_prereq.entry(after) {
+            Entry::Vacant(e) => {
+                e.insert(vec![before]);
+            },
+            Entry::Occupied(mut e) => {
+                e.get_mut().push(before);
+            }
+        }
+    }
+
+    for line in sections.next().unwrap_or_default().lines {
+        if let Some(update) = diagnostics.check(line, line.list(','))? {
+            updates.push(update)
+        }
+    }
+
+    if let Some(extra) = sections.next() {
+        return Err(extra.lines[0].error("expected only ordering rules and updates").into());
+    }
+
+    Ok(PrintQueue { task_prereq, updates })
+}
+
+fn middle_value(update: &[u64]) -> u64 {
+    update[update.len() / 2]
+}
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Parsed = PrintQueue;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<PrintQueue> {
+        parse(input)
+    }
+
+    fn parse_with(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<PrintQueue> {
+        parse_with(input, diagnostics)
+    }
+
+    fn part1(&self, queue: &PrintQueue) -> Result<u64> {
+        Ok(queue
+            .updates
+            .iter()
+            .filter(|update| is_valid(update, &queue.task_prereq))
+            .map(|update| middle_value(update))
+            .sum())
+    }
+
+    fn part2(&self, queue: &PrintQueue) -> Result<u64> {
+        let mut ans = 0;
+        for update in &queue.updates {
+            if is_valid(update, &queue.task_prereq) {
+                continue;
+            }
+            let mut update = update.clone();
+            fix_order(&mut update, &queue.task_prereq)?;
+            ans += middle_value(&update);
+        }
+        Ok(ans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part1(&parsed).unwrap(), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part2(&parsed).unwrap(), 123);
+    }
+
+    #[test]
+    fn looping_rules_are_an_error_not_a_hang() {
+        let parsed = Day5.parse("47|53\n53|47\n\n47,53\n").unwrap();
+        assert_eq!(Day5.part1(&parsed).unwrap(), 0);
+        assert!(Day5.part2(&parsed).is_err());
+
+        let parsed = Day5.parse("47|47\n\n47,47,47\n").unwrap();
+        assert!(Day5.part2(&parsed).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+
+        #[test]
+        fn parse_never_panics_on_rule_like_input(input in "[0-9|,\n ]{0,64}") {
+            let _ = parse(&input);
+        }
+
+        #[test]
+        fn parse_reads_well_formed_rules(rules in prop::collection::vec((0u64..100, 0u64..100), 1..8)) {
+            let input: String = rules.iter().map(|(before, after)| format!("{before}|{after}\n")).collect();
+            let queue = parse(&format!("{input}\n1,2,3\n")).unwrap();
+            for (before, after) in rules {
+                prop_assert!(queue.task_prereq[&after].contains(&before));
+            }
+            prop_assert_eq!(queue.updates, vec![vec![1, 2, 3]]);
+        }
+    }
+}
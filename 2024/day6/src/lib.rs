@@ -0,0 +1,459 @@
+use anyhow::{bail, Result};
+
+use aoc_core::{
+    AocError, Board, Color, DirectedPointSet, Direction, ErrorKind, Grid, GridParser, MarkedGrid,
+    Part, Point, PointSet, Scene, Solution,
+};
+use rayon::prelude::*;
+
+pub struct Day6;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct State {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Obstruction,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, AocError> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Obstruction),
+            other => Err(ErrorKind::InvalidCharacter(other).into()),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> char {
+        match tile {
+            Tile::Open => '.',
+            Tile::Obstruction => '#',
+        }
+    }
+}
+
+/// Walks the guard until it leaves the map, pushing every state it passes through onto `trace`.
+///
+/// On boards without an exit, such as a wrapping grid, the walk stops once the guard repeats a state.
+pub fn simulate_guard_path<B: Board<Tile> + AsRef<Grid<Tile>>>(
+    board: &B,
+    start: Point,
+    mut direction: Direction,
+    mut trace: Option<&mut Vec<State>>,
+) -> Result<PointSet> {
+    let mut visited = PointSet::for_grid(board.as_ref());
+    let mut visited_states = DirectedPointSet::for_grid(board.as_ref(), &Direction::ORTHOGONAL);
+    let mut current_position = start;
+
+    visited.insert(current_position);
+
+    while visited_states.insert(current_position, direction) {
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push(State {
+                position: current_position,
+                direction,
+            });
+        }
+
+        // Determine the next position based on the current direction, exiting the loop if
+        // the guard moves out of bounds
+        let Some((next_position, &tile)) = board.resolve(current_position.step(direction)) else {
+            break;
+        };
+
+        // Check if the next position is open
+        if tile == Tile::Open {
+            // Move to the next position and mark it as visited
+            current_position = next_position;
+            visited.insert(current_position);
+        } else {
+            // Turn right if the next position is blocked
+            direction = direction.turn_right();
+        }
+    }
+
+    Ok(visited)
+}
+
+/// Like [`simulate_guard_path`], but stops with `true` as soon as the guard repeats a state.
+///
+/// `visited_states` is cleared first, so one set can be reused across many simulations.
+pub fn simulate_guard_path_with_loop_detection<B: Board<Tile>>(
+    board: &B,
+    start: Point,
+    mut direction: Direction,
+    obstruction: Option<Point>,
+    visited_states: &mut DirectedPointSet,
+    mut trace: Option<&mut Vec<State>>,
+) -> Result<bool> {
+    visited_states.clear();
+    let mut current_position = start;
+    loop {
+        // Add the current state to visited set
+        if !visited_states.insert(current_position, direction) {
+            return Ok(true);
+        }
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push(State {
+                position: current_position,
+                direction,
+            });
+        }
+
+        let Some((next_position, &tile)) = board.resolve(current_position.step(direction)) else {
+            return Ok(false);
+        };
+
+        if let Some(obs) = obstruction {
+            if next_position == obs {
+                direction = direction.turn_right();
+                continue;
+            }
+        }
+
+        if tile == Tile::Open {
+            current_position = next_position;
+        } else {
+            direction = direction.turn_right();
+        }
+    }
+}
+
+/// Determines valid obstruction positions that would cause the guard to loop.
+pub fn find_obstruction_positions<B: Board<Tile> + AsRef<Grid<Tile>>>(
+    board: &B,
+    guard_start: Point,
+    direction: Direction,
+) -> Result<Vec<Point>> {
+    let mut valid_positions = Vec::new();
+    let mut visited_states = DirectedPointSet::for_grid(board.as_ref(), &Direction::ORTHOGONAL);
+
+    for (candidate, &tile) in board.as_ref().iter() {
+        // Skip starting position
+        if candidate == guard_start || tile != Tile::Open {
+            continue;
+        }
+
+        // Simulate and check for loop
+        let detected_loop = simulate_guard_path_with_loop_detection(
+            board,
+            guard_start,
+            direction,
+            Some(candidate),
+            &mut visited_states,
+            None,
+        )?;
+        if detected_loop {
+            valid_positions.push(candidate);
+        }
+    }
+    Ok(valid_positions)
+}
+
+/// Like [`find_obstruction_positions`], but checks candidates in parallel with one visited set per worker.
+pub fn find_obstruction_positions_concurrent<B>(
+    board: &B,
+    guard_start: Point,
+    direction: Direction,
+) -> Result<Vec<Point>>
+where
+    B: Board<Tile> + AsRef<Grid<Tile>> + Sync,
+{
+    let grid = board.as_ref();
+    let candidates: Vec<Point> = grid
+        .iter()
+        .filter(|&(candidate, &tile)| candidate != guard_start && tile == Tile::Open)
+        .map(|(candidate, _)| candidate)
+        .collect();
+
+    let loops = candidates
+        .par_iter()
+        .map_init(
+            || DirectedPointSet::for_grid(grid, &Direction::ORTHOGONAL),
+            |visited_states, &candidate| {
+                // The simulation clears the set, so each worker reuses one allocation.
+                simulate_guard_path_with_loop_detection(
+                    board,
+                    guard_start,
+                    direction,
+                    Some(candidate),
+                    visited_states,
+                    None,
+                )
+                .map(|detected_loop| detected_loop.then_some(candidate))
+            },
+        )
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(loops.into_iter().flatten().collect())
+}
+
+/// What happens when the guard walks off the edge of the map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Edges {
+    /// The guard leaves the lab, as in the puzzle.
+    #[default]
+    Exit,
+    /// The guard comes back in on the opposite side.
+    Wrap,
+}
+
+#[derive(Clone, Debug)]
+pub struct Lab {
+    pub grid: Grid<Tile>,
+    pub guard_start: Point,
+    pub direction: Direction,
+    pub edges: Edges,
+}
+
+impl Lab {
+    /// The same lab, but with the guard wrapping around at the edges.
+    pub fn wrapping(self) -> Self {
+        Self {
+            edges: Edges::Wrap,
+            ..self
+        }
+    }
+
+    /// Every cell the guard visits.
+    pub fn visited(&self) -> Result<PointSet> {
+        match self.edges {
+            Edges::Exit => simulate_guard_path(&self.grid, self.guard_start, self.direction, None),
+            Edges::Wrap => simulate_guard_path(
+                &self.grid.wrapping(),
+                self.guard_start,
+                self.direction,
+                None,
+            ),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Lab> {
+    let MarkedGrid { grid, markers } = GridParser::new().required('^', '.').parse(input)?;
+
+    let guard_start = markers.require('^')?;
+    let grid = grid.try_map(|&tile| Tile::try_from(tile))?;
+
+    Ok(Lab {
+        grid,
+        guard_start,
+        direction: Direction::Up,
+        edges: Edges::Exit,
+    })
+}
+
+/// Every state the guard passes through, in order.
+#[derive(Clone, Debug)]
+pub struct Patrol {
+    pub steps: Vec<State>,
+    /// The walk ends by repeating a state rather than by leaving the map.
+    pub looped: bool,
+}
+
+/// Records the guard's walk, optionally with an extra obstruction placed at `obstruction`.
+pub fn record_patrol(lab: &Lab, obstruction: Option<Point>) -> Result<Patrol> {
+    let mut steps = Vec::new();
+    let mut visited_states = DirectedPointSet::for_grid(&lab.grid, &Direction::ORTHOGONAL);
+    let (start, direction) = (lab.guard_start, lab.direction);
+    let looped = match lab.edges {
+        Edges::Exit => simulate_guard_path_with_loop_detection(
+            &lab.grid,
+            start,
+            direction,
+            obstruction,
+            &mut visited_states,
+            Some(&mut steps),
+        )?,
+        Edges::Wrap => simulate_guard_path_with_loop_detection(
+            &lab.grid.wrapping(),
+            start,
+            direction,
+            obstruction,
+            &mut visited_states,
+            Some(&mut steps),
+        )?,
+    };
+    Ok(Patrol { steps, looped })
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        _ => '*',
+    }
+}
+
+/// The lab after the guard has taken the first `step + 1` states of `patrol`.
+pub fn patrol_frame(
+    lab: &Lab,
+    patrol: &Patrol,
+    step: usize,
+    obstruction: Option<Point>,
+) -> Result<Scene> {
+    let mut grid = lab.grid.map(|&tile| char::from(tile));
+    let walked = &patrol.steps[..=step.min(patrol.steps.len() - 1)];
+    let guard = walked[walked.len() - 1];
+    grid.set(guard.position, arrow(guard.direction))?;
+
+    let mut scene = Scene::new(grid).layer(
+        "visited",
+        Color::Cyan,
+        Some('X'),
+        walked.iter().map(|state| state.position),
+    );
+    if let Some(obstruction) = obstruction {
+        scene.grid.set(obstruction, '#')?;
+        scene = scene.layer("obstruction", Color::Red, Some('O'), [obstruction]);
+    }
+    Ok(scene.layer("guard", Color::Yellow, None, [guard.position]))
+}
+
+/// Obstructions that trap the guard in a loop, in reading order.
+pub fn obstruction_candidates(lab: &Lab) -> Result<Vec<Point>> {
+    let mut candidates = match lab.edges {
+        Edges::Exit => {
+            find_obstruction_positions_concurrent(&lab.grid, lab.guard_start, lab.direction)?
+        }
+        // A wrapping guard never leaves, so every obstruction would count as a loop.
+        Edges::Wrap => bail!("Every obstruction traps a guard who wraps around the edges"),
+    };
+    candidates.sort_by_key(|candidate| candidate.to_row_col());
+    Ok(candidates)
+}
+
+/// The lab with the guard's patrol, plus the loop-causing obstructions for part 2.
+pub fn scene(lab: &Lab, part: Part) -> Result<Scene> {
+    let mut grid = lab.grid.map(|&tile| char::from(tile));
+    grid.set(lab.guard_start, '^')?;
+
+    let visited = lab.visited()?;
+    let scene = Scene::new(grid).layer("visited", Color::Cyan, Some('X'), visited.iter());
+    Ok(match part {
+        Part::One => scene,
+        Part::Two => scene.layer(
+            "obstruction candidates",
+            Color::Red,
+            Some('O'),
+            obstruction_candidates(lab)?,
+        ),
+    })
+}
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Parsed = Lab;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Lab> {
+        parse(input)
+    }
+
+    fn part1(&self, lab: &Lab) -> Result<usize> {
+        Ok(lab.visited()?.len())
+    }
+
+    fn part2(&self, lab: &Lab) -> Result<usize> {
+        Ok(obstruction_candidates(lab)?.len())
+    }
+
+    fn scene(&self, lab: &Lab, part: Part) -> Result<Option<Scene>> {
+        scene(lab, part).map(Some)
+    }
+
+    fn variant(&self, lab: Lab, name: &str) -> Result<Lab> {
+        match name {
+            "wrap" => Ok(lab.wrapping()),
+            other => bail!("2024 day 6 has no variant '{other}'; the only one is 'wrap'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day6.parse(EXAMPLE).unwrap();
+        assert_eq!(Day6.part1(&parsed).unwrap(), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day6.parse(EXAMPLE).unwrap();
+        assert_eq!(Day6.part2(&parsed).unwrap(), 6);
+    }
+
+    #[test]
+    fn sequential_and_concurrent_obstructions_agree() {
+        let lab = Day6.parse(EXAMPLE).unwrap();
+        let by_row_col = |mut positions: Vec<Point>| {
+            positions.sort_by_key(|position| position.to_row_col());
+            positions
+        };
+        let sequential =
+            find_obstruction_positions(&lab.grid, lab.guard_start, lab.direction).unwrap();
+        let concurrent =
+            find_obstruction_positions_concurrent(&lab.grid, lab.guard_start, lab.direction)
+                .unwrap();
+        assert_eq!(sequential.len(), 6);
+        assert_eq!(by_row_col(sequential), by_row_col(concurrent));
+    }
+
+    #[test]
+    fn scene_layers_match_answers() {
+        let lab = Day6.parse(EXAMPLE).unwrap();
+        let scene = scene(&lab, Part::Two).unwrap();
+        assert_eq!(scene.layer_sizes(), vec![41, 6]);
+    }
+
+    #[test]
+    fn recorded_patrol_replays_the_walk() {
+        let lab = Day6.parse(EXAMPLE).unwrap();
+        let patrol = record_patrol(&lab, None).unwrap();
+        assert!(!patrol.looped);
+        let mut visited = PointSet::for_grid(&lab.grid);
+        visited.extend(patrol.steps.iter().map(|state| state.position));
+        assert_eq!(visited.len(), 41);
+
+        let obstruction = obstruction_candidates(&lab).unwrap()[0];
+        let patrol = record_patrol(&lab, Some(obstruction)).unwrap();
+        assert!(patrol.looped);
+        let last = patrol_frame(&lab, &patrol, usize::MAX, Some(obstruction)).unwrap();
+        assert_eq!(last.grid[obstruction], '#');
+    }
+
+    #[test]
+    fn wrapping_guard_walks_until_it_repeats_itself() {
+        let lab = Day6.parse(EXAMPLE).unwrap().wrapping();
+        let patrol = record_patrol(&lab, None).unwrap();
+        assert!(patrol.looped);
+
+        let mut visited = PointSet::for_grid(&lab.grid);
+        visited.extend(patrol.steps.iter().map(|state| state.position));
+        assert!(visited.len() > 41);
+        assert_eq!(Day6.part1(&lab).unwrap(), visited.len());
+        assert!(Day6.part2(&lab).is_err());
+        assert!(Day6
+            .variant(Day6.parse(EXAMPLE).unwrap(), "spiral")
+            .is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use anyhow::{anyhow, Result};
+use aoc_core::{parse, AocError, Diagnostics, Line, Solution};
+use rayon::prelude::*;
+
+pub struct Day7;
+
+pub struct CalibrationParser;
+
+impl CalibrationParser {
+    pub fn parse(value: &str) -> Result<Vec<u64>, AocError> {
+        Self::parse_line(Line::new(1, value))
+    }
+
+    /// The target value followed by the operands, e.g. `190: 10 19`.
+    pub fn parse_line(line: Line) -> Result<Vec<u64>, AocError> {
+        let (target, operands) = line.key_values::<u64, u64>()?;
+        Ok(std::iter::once(target).chain(operands).collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    pub solution: u64,
+    pub equation: Vec<u64>
+
+}
+
+impl Calibration {
+    pub fn new(value: &str) -> Result<Self, AocError> {
+        Self::from_line(Line::new(1, value))
+    }
+
+    pub fn from_line(line: Line) -> Result<Self, AocError> {
+        let nums = CalibrationParser::parse_line(line)?;
+        let (result, equation) = nums
+            .split_first()
+            .ok_or_else(|| line.error("expected a target value"))?;
+
+        Ok(Self {
+            solution: *result,
+            equation: equation.to_vec(),
+        })
+    }
+
+    pub fn is_valid(&self, strategy: &dyn ValidationStrategy) -> bool {
+        strategy.validate(self)
+    }
+}
+
+pub trait ValidationStrategy: Send + Sync {
+    fn validate(&self, calibration: &Calibration) -> bool;
+}
+
+pub struct Part1Validation; 
+
+impl ValidationStrategy for Part1Validation {
+    fn validate(&self, calibration: &Calibration) -> bool {
+        Self::calculate(1, calibration.equation[0], calibration)
+    }
+}
+
+impl Part1Validation {
+    fn calculate(idx: usize, acc: u64, calibration: &Calibration) -> bool {
+        if idx == calibration.equation.len() && acc == calibration.solution {
+            return true
+        }
+
+        if idx >= calibration.equation.len() {
+            return false
+        }
+
+        let next_value = calibration.equation[idx];
+
+        if Self::fits(acc.checked_mul(next_value), idx, calibration) {
+            return true
+        }
+
+        if Self::fits(acc.checked_add(next_value), idx, calibration) {
+            return true
+        }
+        false
+    }
+
+    /// Whether `value` didn't overflow, stays within the target and leads to it.
+    fn fits(value: Option<u64>, idx: usize, calibration: &Calibration) -> bool {
+        value.is_some_and(|value| {
+            value <= calibration.solution && Self::calculate(idx + 1, value, calibration)
+        })
+    }
+}
+
+pub struct Part2Validation;
+
+impl ValidationStrategy for Part2Validation {
+    fn validate(&self, calibration: &Calibration) -> bool {
+        Self::calculate(1, calibration.equation[0], calibration)
+    }
+}
+
+impl Part2Validation {
+    fn calculate(idx: usize, acc: u64, calibration: &Calibration) -> bool {
+        if idx == calibration.equation.len() && acc == calibration.solution {
+            return true
+        }
+
+        if idx >= calibration.equation.len() {
+            return false
+        }
+
+        let next_value = calibration.equation[idx];
+
+        if Self::fits(acc.checked_mul(next_value), idx, calibration) {
+            return true
+        }
+
+        if Self::fits(acc.checked_add(next_value), idx, calibration) {
+            return true
+        }
+
+        if Self::fits(concat_nums(acc, next_value), idx, calibration) {
+            return true
+        }
+        false
+    }
+
+    /// As [`Part1Validation::fits`], continuing with all three operators.
+    fn fits(value: Option<u64>, idx: usize, calibration: &Calibration) -> bool {
+        value.is_some_and(|value| {
+            value <= calibration.solution && Self::calculate(idx + 1, value, calibration)
+        })
+    }
+
+}
+
+
+/// The digits of `num1` followed by those of `num2`, or `None` if that overflows a u64.
+pub fn concat_nums(num1: u64, num2: u64) -> Option<u64> {
+    let digits = num2.checked_ilog10().unwrap_or(0) + 1;
+    num1.checked_mul(10u64.checked_pow(digits)?)?.checked_add(num2)
+}
+
+pub fn calculate_solution(
+    calibrations: &[Calibration],
+    strategy: &dyn ValidationStrategy,
+) -> Result<u64> {
+    calibrations
+        .par_iter()
+        .filter(|calibration| calibration.is_valid(strategy))
+        .map(|calibration| Some(calibration.solution))
+        .try_reduce(|| 0, u64::checked_add)
+        .ok_or_else(|| anyhow!("The calibration total overflows a u64"))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Calibration>> {
+    parse_with(input, &mut Diagnostics::strict())
+}
+
+/// One calibration per line; a lenient parse skips lines that aren't `target: operands`.
+pub fn parse_with(input: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Calibration>> {
+    let mut calibrations = Vec::new();
+    for line in parse::lines(input) {
+        if let Some(calibration) = diagnostics.check(line, Calibration::from_line(line))? {
+            calibrations.push(calibration);
+        }
+    }
+    Ok(calibrations)
+}
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<Calibration>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Calibration>> {
+        parse(input)
+    }
+
+    fn parse_with(
+        &self,
+        input: &str,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Vec<Calibration>> {
+        parse_with(input, diagnostics)
+    }
+
+    fn part1(&self, calibrations: &Vec<Calibration>) -> Result<u64> {
+        calculate_solution(calibrations, &Part1Validation)
+    }
+
+    fn part2(&self, calibrations: &Vec<Calibration>) -> Result<u64> {
+        calculate_solution(calibrations, &Part2Validation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day7.parse(EXAMPLE).unwrap();
+        assert_eq!(Day7.part1(&parsed).unwrap(), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day7.parse(EXAMPLE).unwrap();
+        assert_eq!(Day7.part2(&parsed).unwrap(), 11387);
+    }
+
+    #[test]
+    fn lenient_parse_skips_malformed_lines() {
+        let input = "190: 10 19\n3267 81 40 27\n83: 17 5\n";
+        let err = parse(input).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, col 5: expected ':' after the key");
+
+        let mut diagnostics = Diagnostics::lenient();
+        let calibrations = parse_with(input, &mut diagnostics).unwrap();
+        assert_eq!(calibrations.len(), 2);
+        assert_eq!(diagnostics.skipped().len(), 1);
+        assert_eq!(diagnostics.skipped()[0].line, 2);
+    }
+
+    #[test]
+    fn concat_nums_joins_digits() {
+        assert_eq!(concat_nums(15, 6), Some(156));
+        assert_eq!(concat_nums(48, 10), Some(4810));
+        assert_eq!(concat_nums(7, 0), Some(70));
+        assert_eq!(concat_nums(1, u64::MAX), None);
+        assert_eq!(concat_nums(10_000_000_000, 10_000_000_000), None);
+    }
+
+    #[test]
+    fn overflowing_operands_just_fail_to_calibrate() {
+        let parsed = Day7.parse("5: 10000000000 10000000000\n190: 10 19\n").unwrap();
+        assert_eq!(Day7.part1(&parsed).unwrap(), 190);
+        assert_eq!(Day7.part2(&parsed).unwrap(), 190);
+
+        let parsed = Day7.parse(&format!("{0}: {0}\n{0}: {0}\n", u64::MAX)).unwrap();
+        assert!(Day7.part1(&parsed).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn calibration_parser_never_panics(input in "\\PC*") {
+            let _ = CalibrationParser::parse(&input);
+        }
+
+        #[test]
+        fn calibration_parser_never_panics_on_calibration_like_input(input in "[0-9: ]{0,32}") {
+            let _ = CalibrationParser::parse(&input);
+        }
+
+        #[test]
+        fn calibration_parser_reads_well_formed_lines(target: u64, operands in prop::collection::vec(any::<u64>(), 1..8)) {
+            let line = format!("{target}: {}", operands.iter().map(u64::to_string).collect::<Vec<_>>().join(" "));
+            let nums = CalibrationParser::parse(&line).unwrap();
+            prop_assert_eq!(nums[0], target);
+            prop_assert_eq!(&nums[1..], &operands[..]);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use aoc_core::{
+    Board, Color, Grid, GridParser, MarkedGrid, Part, Point, PointSet, Scene, Solution, SparseGrid,
+};
+
+pub struct Day8;
+
+/// Where antinodes may land.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Plane {
+    /// Only inside the map, as in the puzzle.
+    #[default]
+    Bounded,
+    /// Anywhere; the map is an empty plane beyond its edges.
+    Infinite,
+}
+
+#[derive(Clone, Debug)]
+pub struct City {
+    pub grid: Grid<char>,
+    pub antennas: HashMap<char, Vec<Point>>,
+    pub plane: Plane,
+}
+
+impl City {
+    /// The same city, with antinodes allowed to fall outside the map.
+    pub fn infinite(self) -> Self {
+        Self {
+            plane: Plane::Infinite,
+            ..self
+        }
+    }
+}
+
+pub fn is_valid_part1(city: &impl Board<char>, position: Point, antena: &char) -> bool {
+    city.tile(position).is_some_and(|value| value != antena)
+}
+
+pub fn is_valid_part2(city: &Grid<char>, position: Point) -> bool {
+    city.in_bounds(position)
+}
+
+pub fn generate_anti_node(p1: Point, p2: Point) -> (Point, Point) {
+    let delta = p2 - p1;
+    (p1 - delta, p2 + delta)
+}
+
+pub fn generate_anti_node_part2(p1: Point, p2: Point, max_rows: i32, max_cols: i32) -> Vec<Point> {
+    let delta = p2 - p1;
+    let mut positions: Vec<Point> = vec![p1, p2];
+
+    let mut current = p1 - delta;
+    while current.row() >= 0 && current.col() >= 0 {
+        positions.push(current);
+        current -= delta;
+    }
+
+    let mut current = p2 + delta;
+    while current.row() < max_rows && current.col() < max_cols {
+        positions.push(current);
+        current += delta;
+    }
+
+    positions
+}
+
+/// Antinodes produced by the antennas of a single frequency, possibly repeated.
+pub fn antinodes_part1(city: &impl Board<char>, antena: &char, positions: &[Point]) -> Vec<Point> {
+    let mut seen = Vec::new();
+    for i in 0..positions.len() {
+        for j in i..positions.len() {
+            let (new_position1, new_position2) = generate_anti_node(positions[i], positions[j]) ;
+            if is_valid_part1(city, new_position1, antena) {
+                seen.push(new_position1);
+            }
+            if is_valid_part1(city, new_position2, antena) {
+                seen.push(new_position2);
+            }
+        }
+    }
+
+    seen
+}
+
+/// Resonant antinodes produced by the antennas of a single frequency.
+pub fn antinodes_part2(city: &Grid<char>, positions: &[Point]) -> PointSet {
+    let mut seen = PointSet::for_grid(city);
+    for i in 0..positions.len() {
+        for j in i+1..positions.len() {
+            let new_positions = generate_anti_node_part2(positions[i], positions[j], city.height() as i32, city.width() as i32);
+            for new_position in new_positions {
+                if is_valid_part2(city, new_position) {
+                    seen.insert(new_position);
+                }
+            }
+        }
+    }
+
+    seen
+}
+
+pub fn part1(city: &Grid<char>, antena_map: &HashMap<char, Vec<Point>>) -> usize {
+    let mut seen = PointSet::for_grid(city);
+    for (antena, positions) in antena_map {
+        seen.extend(antinodes_part1(city, antena, positions));
+    }
+
+    seen.len()
+}
+
+/// Part 1 on a plane without edges, where antinodes may fall outside the map.
+pub fn part1_unbounded(city: &SparseGrid<char>, antena_map: &HashMap<char, Vec<Point>>) -> usize {
+    let mut seen: HashSet<Point> = HashSet::new();
+    for (antena, positions) in antena_map {
+        seen.extend(antinodes_part1(city, antena, positions));
+    }
+
+    seen.len()
+}
+
+pub fn part2(city: &Grid<char>, antena_map: &HashMap<char, Vec<Point>>) -> usize {
+    let mut seen = PointSet::for_grid(city);
+    for positions in antena_map.values() {
+        seen.union_with(&antinodes_part2(city, positions));
+    }
+
+    seen.len()
+}
+
+/// The city with one antinode layer per frequency; a bounded map can't show an infinite plane.
+pub fn scene(city: &City, part: Part) -> Result<Scene> {
+    if city.plane == Plane::Infinite {
+        bail!("Antinodes past the edge of an infinite plane can't be drawn on the map");
+    }
+    let mut frequencies: Vec<_> = city.antennas.iter().collect();
+    frequencies.sort_by_key(|(antena, _)| **antena);
+
+    let mut scene = Scene::new(city.grid.clone());
+    for (index, (antena, positions)) in frequencies.into_iter().enumerate() {
+        let antinodes = match part {
+            Part::One => antinodes_part1(&city.grid, antena, positions),
+            Part::Two => antinodes_part2(&city.grid, positions).iter().collect(),
+        };
+        scene = scene.layer(
+            format!("antinodes of '{antena}'"),
+            Color::nth(index),
+            Some('#'),
+            antinodes,
+        );
+    }
+    Ok(scene)
+}
+
+pub fn parse(input: &str) -> Result<City> {
+    let MarkedGrid { grid, markers } = GridParser::new()
+        .group(|glyph| glyph.is_alphanumeric())
+        .parse(input)?;
+    Ok(City {
+        grid,
+        antennas: markers.into_groups(),
+        plane: Plane::Bounded,
+    })
+}
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Parsed = City;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<City> {
+        parse(input)
+    }
+
+    fn part1(&self, city: &City) -> Result<usize> {
+        Ok(match city.plane {
+            Plane::Bounded => part1(&city.grid, &city.antennas),
+            Plane::Infinite => {
+                let plane = SparseGrid::from_grid(&city.grid, '.');
+                part1_unbounded(&plane, &city.antennas)
+            }
+        })
+    }
+
+    fn part2(&self, city: &City) -> Result<usize> {
+        match city.plane {
+            Plane::Bounded => Ok(part2(&city.grid, &city.antennas)),
+            Plane::Infinite => bail!("Resonant antinodes never end on an infinite plane"),
+        }
+    }
+
+    fn scene(&self, city: &City, part: Part) -> Result<Option<Scene>> {
+        scene(city, part).map(Some)
+    }
+
+    fn variant(&self, city: City, name: &str) -> Result<City> {
+        match name {
+            "infinite" => Ok(city.infinite()),
+            other => bail!("2024 day 8 has no variant '{other}'; the only one is 'infinite'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part1(&parsed).unwrap(), 14);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part2(&parsed).unwrap(), 34);
+    }
+
+    #[test]
+    fn infinite_plane_keeps_antinodes_past_the_edge() {
+        let city = Day8.variant(Day8.parse(EXAMPLE).unwrap(), "infinite").unwrap();
+        assert_eq!(Day8.part1(&city).unwrap(), 17);
+        assert!(Day8.part2(&city).is_err());
+    }
+
+    #[test]
+    fn infinite_plane_has_no_scene() {
+        let city = Day8.parse(EXAMPLE).unwrap();
+        assert!(Day8.scene(&city, Part::Two).unwrap().is_some());
+
+        let city = city.infinite();
+        assert!(Day8.scene(&city, Part::One).is_err());
+        assert!(Day8.scene(&city, Part::Two).is_err());
+    }
+}
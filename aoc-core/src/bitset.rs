@@ -0,0 +1,286 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed-capacity set of small integers, one bit each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+}
+
+impl BitSet {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(WORD_BITS)],
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn locate(&self, index: usize) -> (usize, u64) {
+        assert!(
+            index < self.capacity,
+            "index {index} is out of range for a bitset of {}",
+            self.capacity
+        );
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    /// Adds `index`, returning whether it was newly added.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        added
+    }
+
+    /// Removes `index`, returning whether it was present.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        let present = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        present
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < self.capacity && {
+            let (word, mask) = self.locate(index);
+            self.words[word] & mask != 0
+        }
+    }
+
+    /// Empties the set while keeping its allocation.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Adds every member of `other`, which must have the same capacity.
+    pub fn union_with(&mut self, other: &BitSet) {
+        assert_eq!(self.capacity, other.capacity, "bitset capacities differ");
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Members in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut remaining = word;
+            std::iter::from_fn(move || {
+                (remaining != 0).then(|| {
+                    let bit = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    index * WORD_BITS + bit
+                })
+            })
+        })
+    }
+}
+
+/// The reading-order index of `pos` in a `width` x `height` grid, if it lies inside.
+fn cell_index(pos: Point, width: usize, height: usize) -> Option<usize> {
+    let in_bounds =
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < width && (pos.y as usize) < height;
+    in_bounds.then(|| pos.y as usize * width + pos.x as usize)
+}
+
+/// A set of cells of a `width` x `height` grid; points outside the grid are never members.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PointSet {
+    bits: BitSet,
+    width: usize,
+    height: usize,
+}
+
+impl PointSet {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            bits: BitSet::new(width * height),
+            width,
+            height,
+        }
+    }
+
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.width(), grid.height())
+    }
+
+    fn index(&self, pos: Point) -> Option<usize> {
+        cell_index(pos, self.width, self.height)
+    }
+
+    /// Adds `pos`, returning whether it was newly added; points outside the grid are ignored.
+    pub fn insert(&mut self, pos: Point) -> bool {
+        self.index(pos).is_some_and(|index| self.bits.insert(index))
+    }
+
+    pub fn remove(&mut self, pos: Point) -> bool {
+        self.index(pos).is_some_and(|index| self.bits.remove(index))
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index(pos)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn union_with(&mut self, other: &PointSet) {
+        self.bits.union_with(&other.bits);
+    }
+
+    /// Members in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        let width = self.width;
+        self.bits
+            .iter()
+            .map(move |index| Point::new((index % width) as i32, (index / width) as i32))
+    }
+}
+
+impl Extend<Point> for PointSet {
+    fn extend<I: IntoIterator<Item = Point>>(&mut self, iter: I) {
+        for pos in iter {
+            self.insert(pos);
+        }
+    }
+}
+
+/// A set of (cell, heading) pairs of a grid, such as the states of a walker.
+///
+/// Each cell takes one bit per heading the set was made for, so a walker that only turns
+/// orthogonally should pass [`Direction::ORTHOGONAL`] rather than [`Direction::ALL`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectedPointSet {
+    bits: BitSet,
+    width: usize,
+    height: usize,
+    /// Each direction's slot within a cell, or `None` if it isn't tracked.
+    slots: [Option<u8>; Direction::ALL.len()],
+    headings: usize,
+}
+
+impl DirectedPointSet {
+    pub fn new(width: usize, height: usize, headings: &[Direction]) -> Self {
+        let mut slots = [None; Direction::ALL.len()];
+        for (slot, &heading) in headings.iter().enumerate() {
+            slots[heading as usize] = Some(slot as u8);
+        }
+        Self {
+            bits: BitSet::new(width * height * headings.len()),
+            width,
+            height,
+            slots,
+            headings: headings.len(),
+        }
+    }
+
+    pub fn for_grid<T>(grid: &Grid<T>, headings: &[Direction]) -> Self {
+        Self::new(grid.width(), grid.height(), headings)
+    }
+
+    fn index(&self, pos: Point, direction: Direction) -> Option<usize> {
+        let slot = self.slots[direction as usize]
+            .unwrap_or_else(|| panic!("{direction:?} is not a heading this set tracks"));
+        cell_index(pos, self.width, self.height).map(|cell| cell * self.headings + slot as usize)
+    }
+
+    /// Adds the pair, returning whether it was newly added; points outside the grid are ignored.
+    pub fn insert(&mut self, pos: Point, direction: Direction) -> bool {
+        self.index(pos, direction)
+            .is_some_and(|index| self.bits.insert(index))
+    }
+
+    pub fn contains(&self, pos: Point, direction: Direction) -> bool {
+        self.index(pos, direction)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitset_tracks_membership_across_words() {
+        let mut bits = BitSet::new(130);
+        assert!(bits.insert(0));
+        assert!(bits.insert(64));
+        assert!(bits.insert(129));
+        assert!(!bits.insert(64));
+        assert_eq!(bits.iter().collect::<Vec<_>>(), vec![0, 64, 129]);
+        assert!(bits.remove(64));
+        assert!(!bits.contains(64));
+        assert!(!bits.contains(500));
+        assert_eq!(bits.len(), 2);
+        bits.clear();
+        assert!(bits.is_empty());
+    }
+
+    #[test]
+    fn point_sets_ignore_points_outside_the_grid() {
+        let mut seen = PointSet::new(3, 2);
+        assert!(seen.insert(Point::new(2, 1)));
+        assert!(!seen.insert(Point::new(3, 0)));
+        assert!(!seen.insert(Point::new(0, -1)));
+        seen.extend([Point::new(0, 0), Point::new(2, 1)]);
+        assert_eq!(
+            seen.iter().collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(2, 1)]
+        );
+
+        let mut states = DirectedPointSet::new(3, 2, &Direction::ORTHOGONAL);
+        assert!(states.insert(Point::new(1, 1), Direction::Up));
+        assert!(states.insert(Point::new(1, 1), Direction::Left));
+        assert!(states.insert(Point::new(2, 1), Direction::Left));
+        assert!(!states.insert(Point::new(1, 1), Direction::Up));
+        assert!(!states.insert(Point::new(3, 1), Direction::Up));
+        assert!(!states.contains(Point::new(1, 1), Direction::Down));
+        assert_eq!(states.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "UpLeft is not a heading this set tracks")]
+    fn directed_sets_refuse_untracked_headings() {
+        let mut states = DirectedPointSet::new(3, 2, &Direction::ORTHOGONAL);
+        states.insert(Point::new(1, 1), Direction::UpLeft);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// A surface that can be walked cell by cell, with its own rule for what lies past the edge.
+pub trait Board<T> {
+    /// The canonical position `pos` refers to, or `None` when it is off the board.
+    fn locate(&self, pos: Point) -> Option<Point>;
+
+    /// The tile at `pos` after applying the edge rule.
+    fn tile(&self, pos: Point) -> Option<&T>;
+
+    /// Both the canonical position and its tile.
+    fn resolve(&self, pos: Point) -> Option<(Point, &T)> {
+        let pos = self.locate(pos)?;
+        Some((pos, self.tile(pos)?))
+    }
+}
+
+/// Bounded: stepping past an edge leaves the board.
+impl<T> Board<T> for Grid<T> {
+    fn locate(&self, pos: Point) -> Option<Point> {
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    fn tile(&self, pos: Point) -> Option<&T> {
+        self.get(pos)
+    }
+}
+
+/// A toroidal view of a grid: stepping past an edge comes back in on the opposite side.
+#[derive(Clone, Copy, Debug)]
+pub struct Wrapping<'a, T>(&'a Grid<T>);
+
+impl<T> Grid<T> {
+    pub fn wrapping(&self) -> Wrapping<'_, T> {
+        Wrapping(self)
+    }
+}
+
+impl<T> Board<T> for Wrapping<'_, T> {
+    fn locate(&self, pos: Point) -> Option<Point> {
+        let (width, height) = (self.0.width() as i32, self.0.height() as i32);
+        Some(Point::new(
+            pos.x.rem_euclid(width),
+            pos.y.rem_euclid(height),
+        ))
+    }
+
+    fn tile(&self, pos: Point) -> Option<&T> {
+        self.0.get(self.locate(pos)?)
+    }
+}
+
+impl<T> AsRef<Grid<T>> for Wrapping<'_, T> {
+    fn as_ref(&self) -> &Grid<T> {
+        self.0
+    }
+}
+
+impl<T> AsRef<Grid<T>> for Grid<T> {
+    fn as_ref(&self) -> &Grid<T> {
+        self
+    }
+}
+
+/// An unbounded plane that only stores the cells that differ from `background`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    background: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(background: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            background,
+        }
+    }
+
+    /// Copies the cells of `grid` that differ from `background`.
+    pub fn from_grid(grid: &Grid<T>, background: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut sparse = Self::new(background);
+        for (pos, tile) in grid.iter() {
+            sparse.set(pos, tile.clone());
+        }
+        sparse
+    }
+
+    pub fn get(&self, pos: Point) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.background)
+    }
+
+    /// Stores `value` at `pos`; setting the background clears the cell.
+    pub fn set(&mut self, pos: Point, value: T)
+    where
+        T: PartialEq,
+    {
+        if value == self.background {
+            self.cells.remove(&pos);
+        } else {
+            self.cells.insert(pos, value);
+        }
+    }
+
+    /// The stored cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&pos, tile)| (pos, tile))
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest and largest corners of the stored cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), &pos| {
+            (
+                Point::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Point::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }))
+    }
+}
+
+impl<T> Board<T> for SparseGrid<T> {
+    fn locate(&self, pos: Point) -> Option<Point> {
+        Some(pos)
+    }
+
+    fn tile(&self, pos: Point) -> Option<&T> {
+        Some(self.get(pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_rules() {
+        let grid: Grid<char> = "ab\ncd\n".parse().unwrap();
+        assert_eq!(grid.tile(Point::new(2, 0)), None);
+        assert_eq!(grid.wrapping().tile(Point::new(2, 0)), Some(&'a'));
+        assert_eq!(grid.wrapping().tile(Point::new(-1, -1)), Some(&'d'));
+        assert_eq!(
+            grid.wrapping().locate(Point::new(-3, 5)),
+            Some(Point::new(1, 1))
+        );
+
+        let mut sparse = SparseGrid::from_grid(&grid, 'a');
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(sparse.tile(Point::new(-100, 7)), Some(&'a'));
+        sparse.set(Point::new(-2, 9), 'z');
+        sparse.set(Point::new(1, 0), 'a');
+        assert_eq!(sparse.get(Point::new(1, 0)), &'a');
+        assert_eq!(sparse.bounds(), Some((Point::new(-2, 1), Point::new(1, 9))));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::error::AocError;
+use crate::parse::Line;
+
+/// How a parser treats malformed lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// The first malformed line fails the parse.
+    #[default]
+    Strict,
+    /// Malformed lines are skipped and recorded.
+    Lenient,
+}
+
+/// A malformed line that a lenient parse stepped over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Skipped {
+    pub line: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+/// Collects what a lenient parse skipped.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    strictness: Strictness,
+    skipped: Vec<Skipped>,
+}
+
+impl Diagnostics {
+    pub fn new(strictness: Strictness) -> Self {
+        Self {
+            strictness,
+            skipped: Vec::new(),
+        }
+    }
+
+    pub fn strict() -> Self {
+        Self::new(Strictness::Strict)
+    }
+
+    pub fn lenient() -> Self {
+        Self::new(Strictness::Lenient)
+    }
+
+    pub fn strictness(&self) -> Strictness {
+        self.strictness
+    }
+
+    /// Passes a parsed line through; a failure is returned when strict, and recorded and skipped when lenient.
+    pub fn check<T>(
+        &mut self,
+        line: Line,
+        parsed: Result<T, AocError>,
+    ) -> Result<Option<T>, AocError> {
+        match parsed {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => match self.strictness {
+                Strictness::Strict => Err(err),
+                Strictness::Lenient => {
+                    self.skipped.push(Skipped {
+                        line: line.number,
+                        text: line.text.to_string(),
+                        reason: err.on_line(line.number).to_string(),
+                    });
+                    Ok(None)
+                }
+            },
+        }
+    }
+
+    pub fn skipped(&self) -> &[Skipped] {
+        &self.skipped
+    }
+
+    pub fn into_skipped(self) -> Vec<Skipped> {
+        self.skipped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lenient_records_what_strict_rejects() {
+        let line = Line::new(4, "3 x");
+        let mut strict = Diagnostics::strict();
+        assert!(strict.check(line, line.columns::<u32, 2>()).is_err());
+
+        let mut lenient = Diagnostics::lenient();
+        assert_eq!(lenient.check(line, line.columns::<u32, 2>()).unwrap(), None);
+        let good = Line::new(5, "3 4");
+        assert_eq!(
+            lenient.check(good, good.columns::<u32, 2>()).unwrap(),
+            Some([3, 4])
+        );
+        assert_eq!(
+            lenient.skipped(),
+            &[Skipped {
+                line: 4,
+                text: "3 x".to_string(),
+                reason: "line 4, col 3: invalid value 'x': invalid digit found in string"
+                    .to_string(),
+            }]
+        );
+    }
+}
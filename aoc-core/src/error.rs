@@ -0,0 +1,168 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+
+use thiserror::Error;
+
+/// Where in the input a problem was found; any part may be unknown.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Location {
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub col: Option<usize>,
+}
+
+impl Location {
+    pub fn is_unknown(&self) -> bool {
+        self.file.is_none() && self.line.is_none() && self.col.is_none()
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(file) = &self.file {
+            parts.push(file.clone());
+        }
+        if let Some(line) = self.line {
+            parts.push(format!("line {line}"));
+        }
+        if let Some(col) = self.col {
+            parts.push(format!("col {col}"));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// What went wrong with an input.
+#[derive(Debug, Error)]
+pub enum ErrorKind {
+    #[error("cannot read input: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Invalid(String),
+    #[error("position ({x}, {y}) is out of bounds")]
+    OutOfBounds { x: i32, y: i32 },
+    #[error("invalid character '{0}' in grid")]
+    InvalidCharacter(char),
+    #[error("grid cannot be empty")]
+    EmptyGrid,
+    #[error("row {row} has {found} columns, expected {expected}")]
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("expected {expected} cells for the grid, got {found}")]
+    SizeMismatch { expected: usize, found: usize },
+    #[error("required marker '{0}' not found in the grid")]
+    MissingMarker(char),
+    #[error("duplicate marker '{glyph}' (first seen at line {first_line}, col {first_col})")]
+    DuplicateMarker {
+        glyph: char,
+        first_line: usize,
+        first_col: usize,
+    },
+}
+
+/// The error every day and parser reports bad input with.
+#[derive(Debug)]
+pub struct AocError {
+    pub kind: ErrorKind,
+    pub location: Location,
+}
+
+impl AocError {
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            location: Location::default(),
+        }
+    }
+
+    /// Input that doesn't have the expected shape.
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Invalid(message.into()))
+    }
+
+    /// Sets the 1-based line, unless one is already known.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.location.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the 1-based line and column, unless a line is already known.
+    pub fn at(mut self, line: usize, col: usize) -> Self {
+        if self.location.line.is_none() {
+            self.location.line = Some(line);
+            self.location.col = Some(col);
+        }
+        self
+    }
+
+    /// Sets the input file, unless one is already known.
+    pub fn in_file(mut self, file: impl Display) -> Self {
+        self.location.file.get_or_insert_with(|| file.to_string());
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.location.is_unknown() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.location, self.kind)
+        }
+    }
+}
+
+// The kind's own cause is already part of its message.
+impl Error for AocError {}
+
+impl From<ErrorKind> for AocError {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> Self {
+        Self::new(ErrorKind::Io(err))
+    }
+}
+
+/// Points an input error inside `err` at `file`; other errors pass through untouched.
+pub fn in_file(mut err: anyhow::Error, file: impl Display) -> anyhow::Error {
+    if let Some(input_error) = err.downcast_mut::<AocError>() {
+        input_error
+            .location
+            .file
+            .get_or_insert_with(|| file.to_string());
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_prefixes_the_message() {
+        let err = AocError::invalid("expected ':' after target value").at(412, 7);
+        assert_eq!(
+            err.to_string(),
+            "line 412, col 7: expected ':' after target value"
+        );
+        let err = AocError::new(ErrorKind::EmptyGrid);
+        assert_eq!(err.to_string(), "grid cannot be empty");
+        let err = in_file(
+            anyhow::Error::from(AocError::invalid("bad").on_line(3)).context("while parsing"),
+            "inputs/2024/07.txt",
+        );
+        assert_eq!(
+            err.root_cause().to_string(),
+            "inputs/2024/07.txt, line 3: bad"
+        );
+    }
+}
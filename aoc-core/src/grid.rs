@@ -0,0 +1,313 @@
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::{AocError, ErrorKind};
+use crate::geometry::{Direction, Point};
+
+/// A rectangular grid stored row by row in one contiguous buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, AocError> {
+        if width == 0 || height == 0 {
+            return Err(ErrorKind::EmptyGrid.into());
+        }
+        if cells.len() != width * height {
+            return Err(ErrorKind::SizeMismatch {
+                expected: width * height,
+                found: cells.len(),
+            }
+            .into());
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Result<Self, AocError>
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, values) in rows.into_iter().enumerate() {
+            if values.len() != width {
+                return Err(ErrorKind::RaggedRow {
+                    row,
+                    expected: width,
+                    found: values.len(),
+                }
+                .into());
+            }
+            cells.extend(values);
+        }
+        Self::new(width, height, cells)
+    }
+
+    /// Parses one cell per character, skipping blank lines; errors carry the line and column.
+    pub fn parse_with(
+        input: &str,
+        mut parse: impl FnMut(char) -> Result<T, AocError>,
+    ) -> Result<Self, AocError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for row in rows(input) {
+            let (number, line) = row?;
+            let before = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                cells.push(parse(c).map_err(|err| err.at(number, col + 1))?);
+            }
+            let found = cells.len() - before;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(AocError::from(ErrorKind::RaggedRow {
+                        row: height,
+                        expected,
+                        found,
+                    })
+                    .on_line(number))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Point) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    pub fn set(&mut self, pos: Point, value: T) -> Result<(), AocError> {
+        let cell = self
+            .get_mut(pos)
+            .ok_or(ErrorKind::OutOfBounds { x: pos.x, y: pos.y })?;
+        *cell = value;
+        Ok(())
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |index| Point::new((index % width) as i32, (index / width) as i32))
+    }
+
+    /// Iterates over every cell together with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell matching `predicate`, row by row.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// In-bounds orthogonal neighbours of `pos`.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(pos, &Direction::ALL)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        pos: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |&direction| pos.step(direction))
+            .filter(|&next| self.in_bounds(next))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Converts every cell, reporting a failed cell's row and column as its line and col.
+    pub fn try_map<U>(
+        &self,
+        mut f: impl FnMut(&T) -> Result<U, AocError>,
+    ) -> Result<Grid<U>, AocError> {
+        let cells = self
+            .iter()
+            .map(|(pos, cell)| {
+                f(cell).map_err(|err| err.at(pos.y as usize + 1, pos.x as usize + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        })
+    }
+}
+
+/// A grid's lines with their 1-based numbers, so row `y` is always line `y + 1`.
+///
+/// Blank lines may only trail the grid; one before or between rows is an error.
+pub(crate) fn rows(input: &str) -> impl Iterator<Item = Result<(usize, &str), AocError>> {
+    let mut blank = None;
+    input.lines().enumerate().filter_map(move |(index, line)| {
+        if line.is_empty() {
+            blank.get_or_insert(index + 1);
+            return None;
+        }
+        Some(match blank {
+            Some(blank) => Err(AocError::invalid("blank line inside the grid").on_line(blank)),
+            None => Ok((index + 1, line)),
+        })
+    })
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position ({}, {}) is out of bounds", pos.x, pos.y))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position ({}, {}) is out of bounds", pos.x, pos.y))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef\n";
+
+    #[test]
+    fn parses_rows_into_flat_storage() {
+        let grid: Grid<char> = SAMPLE.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn rejects_ragged_and_empty_input() {
+        let ragged = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert!(matches!(
+            ragged.kind,
+            ErrorKind::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(ragged.location.line, Some(2));
+        assert!(matches!(
+            "\n".parse::<Grid<char>>().unwrap_err().kind,
+            ErrorKind::EmptyGrid
+        ));
+    }
+
+    #[test]
+    fn blank_lines_only_trail_the_grid() {
+        assert!("abc\ndef\n\n\n".parse::<Grid<char>>().is_ok());
+        for (gapped, blank) in [("abc\n\ndef\n", 2), ("\nabc\ndef\n", 1)] {
+            let err = gapped.parse::<Grid<char>>().unwrap_err();
+            assert!(matches!(err.kind, ErrorKind::Invalid(_)));
+            assert_eq!(err.location.line, Some(blank));
+        }
+    }
+
+    #[test]
+    fn mapping_errors_point_at_the_cell() {
+        let grid: Grid<char> = SAMPLE.parse().unwrap();
+        let err = grid
+            .try_map(|&c| match c {
+                'e' => Err(AocError::invalid("no e")),
+                c => Ok(c),
+            })
+            .unwrap_err();
+        assert_eq!((err.location.line, err.location.col), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid: Grid<char> = SAMPLE.parse().unwrap();
+        let corner: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 5);
+    }
+}
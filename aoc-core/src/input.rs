@@ -0,0 +1,207 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::Result;
+use memmap2::Mmap;
+
+use crate::error::{self, AocError, ErrorKind};
+
+/// Environment variable that overrides where conventional inputs are looked up.
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Where a puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The conventional location of an input, e.g. `inputs/2024/06.txt`.
+    pub fn conventional(inputs_dir: &Path, year: u16, day: u8) -> Self {
+        InputSource::File(
+            inputs_dir
+                .join(year.to_string())
+                .join(format!("{day:02}.txt")),
+        )
+    }
+
+    /// Memory-maps a file input, or reads stdin into memory, without copying it into a `String`.
+    pub fn load(&self) -> Result<Input> {
+        let loaded = match self {
+            InputSource::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map(|_| Input::from(bytes))
+            }
+            InputSource::File(path) => File::open(path).and_then(|file| Input::map(&file)),
+        };
+        Ok(loaded.map_err(|err| AocError::from(err).in_file(self))?)
+    }
+
+    /// Points an input error raised while handling this source at its file.
+    pub fn locate(&self, err: anyhow::Error) -> anyhow::Error {
+        error::in_file(err, self)
+    }
+}
+
+/// Whether an error from [`InputSource::load`] means the input doesn't exist, rather than that
+/// it couldn't be read.
+pub fn is_missing(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<AocError>().map(|err| &err.kind),
+        Some(ErrorKind::Io(io)) if io.kind() == io::ErrorKind::NotFound
+    )
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// A loaded puzzle input that parsers borrow slices from.
+pub struct Input {
+    data: Data,
+}
+
+enum Data {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Input {
+    fn map(file: &File) -> io::Result<Self> {
+        // Some platforms refuse to map an empty file, and there is nothing to map anyway.
+        if file.metadata()?.len() == 0 {
+            return Ok(Self::from(Vec::new()));
+        }
+        // SAFETY: the map is read-only. Inputs are not expected to change during a run; one
+        // truncated while mapped can still crash the process, the usual caveat of mapping files.
+        let map = unsafe { Mmap::map(file)? };
+        Ok(Self {
+            data: Data::Mapped(map),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.data {
+            Data::Mapped(map) => map,
+            Data::Owned(bytes) => bytes,
+        }
+    }
+
+    /// The input as text; invalid UTF-8 is reported with its line and column.
+    pub fn as_str(&self) -> Result<&str, AocError> {
+        let bytes = self.as_bytes();
+        std::str::from_utf8(bytes).map_err(|err| {
+            let valid = &bytes[..err.valid_up_to()];
+            let line = valid.iter().filter(|&&byte| byte == b'\n').count() + 1;
+            let line_start = valid
+                .iter()
+                .rposition(|&byte| byte == b'\n')
+                .map_or(0, |i| i + 1);
+            // Everything before `valid_up_to` is valid UTF-8, so this never falls back.
+            let col = std::str::from_utf8(&valid[line_start..])
+                .map_or(0, |text| text.chars().count())
+                + 1;
+            AocError::invalid("input is not valid UTF-8").at(line, col)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+}
+
+impl From<Vec<u8>> for Input {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            data: Data::Owned(bytes),
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Self::from(text.into_bytes())
+    }
+}
+
+/// Walks up from `start` to the directory holding the workspace `Cargo.toml`.
+pub fn find_workspace_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// The workspace root when run from inside the workspace, otherwise the current directory.
+pub fn workspace_dir() -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    find_workspace_root(&cwd).unwrap_or(cwd)
+}
+
+/// The directory conventional inputs live in: `$AOC_INPUTS_DIR`, or `inputs/` at the workspace root.
+pub fn default_inputs_dir() -> PathBuf {
+    match env::var_os(INPUTS_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_dir().join("inputs"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_input_borrows_the_file() {
+        let path = env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        fs::write(&path, "3   4\r\n4   3\n\n2   5").unwrap();
+        let input = InputSource::File(path.clone()).load().unwrap();
+
+        assert_eq!(input.as_bytes(), b"3   4\r\n4   3\n\n2   5");
+        assert_eq!(input.as_str().unwrap().lines().count(), 4);
+        drop(input);
+        fs::remove_file(&path).unwrap();
+
+        let missing = InputSource::File(path).load().err().unwrap();
+        assert!(is_missing(&missing));
+        let unreadable = InputSource::File(env::temp_dir()).load().err().unwrap();
+        assert!(!is_missing(&unreadable));
+    }
+
+    #[test]
+    fn reports_where_utf8_breaks() {
+        let input = Input::from(b"ab\ncd\xffe\n".to_vec());
+        let err = input.as_str().unwrap_err();
+        assert_eq!(err.to_string(), "line 2, col 3: input is not valid UTF-8");
+        assert!(Input::from(String::new()).is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+//! Shared building blocks for the Advent of Code solutions.
+
+pub mod bitset;
+pub mod board;
+pub mod diagnostics;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod markers;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod stencil;
+
+pub use bitset::{BitSet, DirectedPointSet, PointSet};
+pub use board::{Board, SparseGrid, Wrapping};
+pub use diagnostics::{Diagnostics, Skipped, Strictness};
+pub use error::{AocError, ErrorKind, Location};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use input::{Input, InputSource};
+pub use markers::{GridParser, MarkedGrid, Markers};
+pub use parse::{Line, Section};
+pub use render::{Color, Layer, Scene};
+pub use solution::{DynSolution, Part, Solution};
+pub use stencil::Pattern;
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use crate::error::{AocError, ErrorKind};
+use crate::geometry::Point;
+use crate::grid::{self, Grid};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Occurs {
+    Once(char),
+    AtMostOnce,
+    Any,
+}
+
+struct Marker {
+    matches: Box<dyn Fn(char) -> bool + Send + Sync>,
+    occurs: Occurs,
+    replacement: Option<char>,
+}
+
+/// Loads a character grid while pulling out the positions of special glyphs.
+///
+/// Each registered glyph is recorded where it appears and can be replaced by
+/// the tile that lies underneath it, e.g. a guard `'^'` standing on open floor.
+#[derive(Default)]
+pub struct GridParser {
+    markers: Vec<Marker>,
+}
+
+impl GridParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `glyph` must appear exactly once and is replaced by `replacement`.
+    pub fn required(self, glyph: char, replacement: char) -> Self {
+        self.marker(move |c| c == glyph, Occurs::Once(glyph), Some(replacement))
+    }
+
+    /// `glyph` may appear at most once and is replaced by `replacement`.
+    pub fn optional(self, glyph: char, replacement: char) -> Self {
+        self.marker(move |c| c == glyph, Occurs::AtMostOnce, Some(replacement))
+    }
+
+    /// Every glyph accepted by `matches` is recorded, grouped by glyph, and left in place.
+    pub fn group(self, matches: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        self.marker(matches, Occurs::Any, None)
+    }
+
+    fn marker(
+        mut self,
+        matches: impl Fn(char) -> bool + Send + Sync + 'static,
+        occurs: Occurs,
+        replacement: Option<char>,
+    ) -> Self {
+        self.markers.push(Marker {
+            matches: Box::new(matches),
+            occurs,
+            replacement,
+        });
+        self
+    }
+
+    pub fn parse(&self, input: &str) -> Result<MarkedGrid, AocError> {
+        let mut rows = Vec::new();
+        let mut found: HashMap<char, Vec<(Point, usize, usize)>> = HashMap::new();
+
+        for row in grid::rows(input) {
+            let (number, line) = row?;
+            let y = rows.len() as i32;
+            let mut row = Vec::with_capacity(line.len());
+            for (x, glyph) in line.chars().enumerate() {
+                let Some(marker) = self.markers.iter().find(|marker| (marker.matches)(glyph))
+                else {
+                    row.push(glyph);
+                    continue;
+                };
+                let (line, col) = (number, x + 1);
+                let seen = found.entry(glyph).or_default();
+                if marker.occurs != Occurs::Any {
+                    if let Some(&(_, first_line, first_col)) = seen.first() {
+                        return Err(AocError::from(ErrorKind::DuplicateMarker {
+                            glyph,
+                            first_line,
+                            first_col,
+                        })
+                        .at(line, col));
+                    }
+                }
+                seen.push((Point::new(x as i32, y), line, col));
+                row.push(marker.replacement.unwrap_or(glyph));
+            }
+            rows.push(row);
+        }
+
+        for marker in &self.markers {
+            if let Occurs::Once(glyph) = marker.occurs {
+                if !found.contains_key(&glyph) {
+                    return Err(ErrorKind::MissingMarker(glyph).into());
+                }
+            }
+        }
+
+        let grid = Grid::from_rows(rows).map_err(|err| match err.kind {
+            ErrorKind::RaggedRow { row, .. } => err.on_line(row + 1),
+            _ => err,
+        })?;
+        let positions = found
+            .into_iter()
+            .map(|(glyph, seen)| (glyph, seen.into_iter().map(|(pos, _, _)| pos).collect()))
+            .collect();
+        Ok(MarkedGrid {
+            grid,
+            markers: Markers { positions },
+        })
+    }
+}
+
+/// A grid with its markers removed, plus where each marker was found.
+#[derive(Clone, Debug)]
+pub struct MarkedGrid {
+    pub grid: Grid<char>,
+    pub markers: Markers,
+}
+
+/// Positions of marker glyphs, in reading order for each glyph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<Point>>,
+}
+
+impl Markers {
+    /// Every position where `glyph` was found.
+    pub fn get(&self, glyph: char) -> &[Point] {
+        self.positions.get(&glyph).map_or(&[], Vec::as_slice)
+    }
+
+    /// The position of a glyph registered with `required` or `optional`.
+    pub fn single(&self, glyph: char) -> Option<Point> {
+        self.get(glyph).first().copied()
+    }
+
+    /// Like [`Markers::single`], but a missing glyph is an error.
+    pub fn require(&self, glyph: char) -> Result<Point, AocError> {
+        self.single(glyph)
+            .ok_or_else(|| ErrorKind::MissingMarker(glyph).into())
+    }
+
+    pub fn into_groups(self) -> HashMap<char, Vec<Point>> {
+        self.positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "..a.\n.^..\na..A\n";
+
+    fn parser() -> GridParser {
+        GridParser::new()
+            .required('^', '.')
+            .group(|c| c.is_alphanumeric())
+    }
+
+    #[test]
+    fn extracts_and_cleans_markers() {
+        let MarkedGrid { grid, markers } = parser().parse(MAP).unwrap();
+        assert_eq!(markers.single('^'), Some(Point::new(1, 1)));
+        assert_eq!(grid[Point::new(1, 1)], '.');
+        assert_eq!(markers.get('a'), &[Point::new(2, 0), Point::new(0, 2)]);
+        assert_eq!(markers.get('A'), &[Point::new(3, 2)]);
+        assert_eq!(grid[Point::new(3, 2)], 'A');
+        assert!(markers.get('z').is_empty());
+    }
+
+    #[test]
+    fn reports_missing_and_duplicate_markers() {
+        let missing = parser().parse("..a.\n").unwrap_err();
+        assert_eq!(
+            missing.to_string(),
+            "required marker '^' not found in the grid"
+        );
+
+        let duplicate = parser().parse(".^..\n...^\n").unwrap_err();
+        assert_eq!(
+            duplicate.to_string(),
+            "line 2, col 4: duplicate marker '^' (first seen at line 1, col 2)"
+        );
+
+        let gapped = parser().parse(".^..\n\n....\n").unwrap_err();
+        assert_eq!(gapped.to_string(), "line 2: blank line inside the grid");
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+/// A non-blank input line and its 1-based line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// A run of non-blank lines, separated from its neighbours by blank lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+
+/// Every non-blank line of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// The input split into blank-line-separated sections.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current = Section::default();
+    for (i, text) in input.lines().enumerate() {
+        if text.trim().is_empty() {
+            if !current.lines.is_empty() {
+                sections.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.lines.push(Line::new(i + 1, text));
+        }
+    }
+    if !current.lines.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// A table of exactly `N` whitespace-separated columns per row.
+pub fn table<T, const N: usize>(input: &str) -> Result<Vec<[T; N]>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lines(input).map(|line| line.columns()).collect()
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// An error about this line.
+    pub fn error(&self, message: impl Into<String>) -> AocError {
+        AocError::invalid(message).on_line(self.number)
+    }
+
+    /// An error about `part`, which must be a slice of this line's text.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> AocError {
+        AocError::invalid(message).at(self.number, self.col(part))
+    }
+
+    /// The 1-based column where `part` starts, counted in characters.
+    fn col(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// The whitespace-separated fields of the line.
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> {
+        self.text.split_whitespace()
+    }
+
+    /// Parses one field of this line.
+    pub fn value<T>(&self, field: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let field = field.trim();
+        field
+            .parse()
+            .map_err(|err| self.error_at(field, format!("invalid value '{field}': {err}")))
+    }
+
+    /// Whitespace-separated numbers, e.g. `7 6 4 2 1`.
+    pub fn numbers<T>(&self) -> Result<Vec<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.fields().map(|field| self.value(field)).collect()
+    }
+
+    /// Values separated by `separator`, e.g. `75,47,61` with `','`.
+    pub fn list<T>(&self, separator: char) -> Result<Vec<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .split(separator)
+            .map(|field| self.value(field))
+            .collect()
+    }
+
+    /// Exactly two values either side of `separator`, e.g. `47|53` with `'|'`.
+    pub fn pair<T>(&self, separator: char) -> Result<(T, T), AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let (left, right) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected '{separator}' between two values")))?;
+        Ok((self.value(left)?, self.value(right)?))
+    }
+
+    /// Exactly `N` whitespace-separated values.
+    pub fn columns<T, const N: usize>(&self) -> Result<[T; N], AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let values = self.numbers::<T>()?;
+        let found = values.len();
+        values.try_into().map_err(|_| {
+            let message = format!("expected {N} columns, found {found}");
+            match self.fields().nth(N) {
+                Some(extra) => self.error_at(extra, message),
+                None => self.error(message),
+            }
+        })
+    }
+
+    /// A `key: values` line with at least one whitespace-separated value.
+    pub fn key_values<K, V>(&self) -> Result<(K, Vec<V>), AocError>
+    where
+        K: FromStr,
+        K::Err: Display,
+        V: FromStr,
+        V::Err: Display,
+    {
+        let Some((key, values)) = self.text.split_once(':') else {
+            let key = self.fields().next().unwrap_or(self.text);
+            return Err(self.error_at(&key[key.len()..], "expected ':' after the key"));
+        };
+        let key = self.value(key)?;
+        let values: Vec<V> = values
+            .split_whitespace()
+            .map(|field| self.value(field))
+            .collect::<Result<_, _>>()?;
+        if values.is_empty() {
+            return Err(self.error_at(&self.text[self.text.len()..], "expected values after ':'"));
+        }
+        Ok((key, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_keep_line_numbers() {
+        let input = "47|53\n97|13\n\n\n75,47,61\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].lines[1].pair::<u32>('|').unwrap(), (97, 13));
+        let update = sections[1].lines[0];
+        assert_eq!(update.number, 5);
+        assert_eq!(update.list::<u32>(',').unwrap(), vec![75, 47, 61]);
+    }
+
+    #[test]
+    fn helpers_parse_common_shapes() {
+        let line = Line::new(1, "3190: 10 19 7");
+        assert_eq!(
+            line.key_values::<u64, u64>().unwrap(),
+            (3190, vec![10, 19, 7])
+        );
+        assert_eq!(
+            table::<u32, 2>("3   4\n4   3\n\n2   5\n").unwrap(),
+            vec![[3, 4], [4, 3], [2, 5]]
+        );
+        assert_eq!(
+            Line::new(1, "7 6 4").numbers::<i32>().unwrap(),
+            vec![7, 6, 4]
+        );
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let err = table::<u32, 2>("3 4\n4 x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, col 3: invalid value 'x': invalid digit found in string"
+        );
+        let err = table::<u32, 2>("3 4\n\n4 3 1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, col 5: expected 2 columns, found 3"
+        );
+        let err = Line::new(9, "190 10 19")
+            .key_values::<u64, u64>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 9, col 4: expected ':' after the key");
+        assert!(Line::new(1, "190:").key_values::<u64, u64>().is_err());
+        assert!(Line::new(1, "47-53").pair::<u32>('|').is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::Write;
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+
+/// Overlay colours, shared by the terminal renderer and the image exporters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// Colours in the order they are handed out to layers without a fixed one.
+    pub const PALETTE: [Color; 6] = [
+        Color::Cyan,
+        Color::Magenta,
+        Color::Yellow,
+        Color::Green,
+        Color::Blue,
+        Color::Red,
+    ];
+
+    /// The `n`th palette colour, wrapping around.
+    pub fn nth(n: usize) -> Color {
+        Self::PALETTE[n % Self::PALETTE.len()]
+    }
+
+    /// The SGR parameter that sets this colour as the background.
+    pub fn ansi_background(self) -> u8 {
+        match self {
+            Color::Red => 41,
+            Color::Green => 42,
+            Color::Yellow => 43,
+            Color::Blue => 44,
+            Color::Magenta => 45,
+            Color::Cyan => 46,
+        }
+    }
+
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Red => [0xe0, 0x4f, 0x4f],
+            Color::Green => [0x4f, 0xb8, 0x5a],
+            Color::Yellow => [0xe8, 0xc5, 0x47],
+            Color::Blue => [0x4a, 0x7b, 0xd9],
+            Color::Magenta => [0xc0, 0x5c, 0xd6],
+            Color::Cyan => [0x3f, 0xc1, 0xc9],
+        }
+    }
+}
+
+/// A named set of highlighted cells.
+#[derive(Clone, Debug)]
+pub struct Layer {
+    pub name: String,
+    pub color: Color,
+    /// Drawn in place of the tile when colour is unavailable; `None` keeps the tile.
+    pub symbol: Option<char>,
+    pub cells: Vec<Point>,
+}
+
+/// A grid of tiles with overlay layers drawn on top; later layers win.
+#[derive(Clone, Debug)]
+pub struct Scene {
+    pub grid: Grid<char>,
+    pub layers: Vec<Layer>,
+    /// De-emphasise tiles that no layer covers.
+    pub fade_unmarked: bool,
+}
+
+impl Scene {
+    pub fn new(grid: Grid<char>) -> Self {
+        Self {
+            grid,
+            layers: Vec::new(),
+            fade_unmarked: false,
+        }
+    }
+
+    pub fn layer(
+        mut self,
+        name: impl Into<String>,
+        color: Color,
+        symbol: Option<char>,
+        cells: impl IntoIterator<Item = Point>,
+    ) -> Self {
+        self.layers.push(Layer {
+            name: name.into(),
+            color,
+            symbol,
+            cells: cells.into_iter().collect(),
+        });
+        self
+    }
+
+    pub fn fade_unmarked(mut self) -> Self {
+        self.fade_unmarked = true;
+        self
+    }
+
+    /// The topmost layer covering each cell.
+    pub fn coverage(&self) -> Grid<Option<&Layer>> {
+        let mut coverage = self.grid.map(|_| None);
+        for layer in &self.layers {
+            for &cell in &layer.cells {
+                if let Some(slot) = coverage.get_mut(cell) {
+                    *slot = Some(layer);
+                }
+            }
+        }
+        coverage
+    }
+
+    /// The number of distinct in-bounds cells in each layer.
+    pub fn layer_sizes(&self) -> Vec<usize> {
+        self.layers
+            .iter()
+            .map(|layer| {
+                let mut cells: Vec<Point> = layer
+                    .cells
+                    .iter()
+                    .copied()
+                    .filter(|&cell| self.grid.in_bounds(cell))
+                    .collect();
+                cells.sort();
+                cells.dedup();
+                cells.len()
+            })
+            .collect()
+    }
+}
+
+/// Draws `scene` for a terminal, with ANSI colours or as plain text, followed by a legend.
+pub fn render_terminal(scene: &Scene, color: bool) -> String {
+    let coverage = scene.coverage();
+    let mut out = String::new();
+    for (pos, &tile) in scene.grid.iter() {
+        if pos.x == 0 && pos.y > 0 {
+            out.push('\n');
+        }
+        match (coverage[pos], color) {
+            (Some(layer), true) => {
+                let _ = write!(
+                    out,
+                    "\x1b[30;{}m{tile}{RESET}",
+                    layer.color.ansi_background()
+                );
+            }
+            (Some(layer), false) => out.push(layer.symbol.unwrap_or(tile)),
+            (None, true) if scene.fade_unmarked => {
+                let _ = write!(out, "{DIM}{tile}{RESET}");
+            }
+            (None, false) if scene.fade_unmarked => out.push('.'),
+            (None, _) => out.push(tile),
+        }
+    }
+    out.push('\n');
+
+    for (layer, size) in scene.layers.iter().zip(scene.layer_sizes()) {
+        out.push('\n');
+        if color {
+            let _ = write!(out, "\x1b[{}m  {RESET}", layer.color.ansi_background());
+        } else {
+            out.push(layer.symbol.unwrap_or('*'));
+        }
+        let _ = write!(out, " {} ({size})", layer.name);
+    }
+    if !scene.layers.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene() -> Scene {
+        Scene::new("..#\n...\n".parse().unwrap())
+            .layer(
+                "path",
+                Color::Cyan,
+                Some('X'),
+                [Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)],
+            )
+            .layer("block", Color::Red, Some('O'), [Point::new(1, 1)])
+    }
+
+    #[test]
+    fn plain_text_uses_layer_symbols() {
+        assert_eq!(
+            render_terminal(&scene(), false),
+            "XX#\n.O.\n\nX path (3)\nO block (1)\n"
+        );
+    }
+
+    #[test]
+    fn colour_output_keeps_tiles() {
+        let rendered = render_terminal(&scene(), true);
+        assert!(rendered.starts_with("\x1b[30;46m.\x1b[0m\x1b[30;46m.\x1b[0m#\n"));
+        assert!(rendered.contains("\x1b[30;41m.\x1b[0m"));
+    }
+
+    #[test]
+    fn faded_plain_text_blanks_unmarked_tiles() {
+        let scene = Scene::new("XMAS\nMMMM\n".parse().unwrap())
+            .layer("XMAS", Color::Green, None, (0..4).map(|x| Point::new(x, 0)))
+            .fade_unmarked();
+        assert_eq!(render_terminal(&scene, false), "XMAS\n....\n\n* XMAS (4)\n");
+    }
+}
@@ -0,0 +1,324 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// Anything that can list the neighbours of a node and what it costs to reach each.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbors(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, u64)>;
+}
+
+/// A graph defined by a closure from a node to its `(neighbour, cost)` pairs.
+pub struct FromFn<N, F> {
+    neighbors: F,
+    node: PhantomData<fn(&N)>,
+}
+
+pub fn from_fn<N, F, I>(neighbors: F) -> FromFn<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    FromFn {
+        neighbors,
+        node: PhantomData,
+    }
+}
+
+impl<N, F, I> Graph for FromFn<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    type Node = N;
+
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = (N, u64)> {
+        (self.neighbors)(node)
+    }
+}
+
+/// A grid seen as a graph of cells; `cost` prices a step between two tiles, `None` meaning blocked.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+    diagonal: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn graph<F>(&self, cost: F) -> GridGraph<'_, T, F>
+    where
+        F: Fn(&T, &T) -> Option<u64>,
+    {
+        GridGraph {
+            grid: self,
+            cost,
+            diagonal: false,
+        }
+    }
+}
+
+impl<T, F> GridGraph<'_, T, F> {
+    /// Also step diagonally.
+    pub fn diagonal(self) -> Self {
+        Self {
+            diagonal: true,
+            ..self
+        }
+    }
+}
+
+impl<T, F> Graph for GridGraph<'_, T, F>
+where
+    F: Fn(&T, &T) -> Option<u64>,
+{
+    type Node = Point;
+
+    fn neighbors(&self, node: &Point) -> impl IntoIterator<Item = (Point, u64)> {
+        let from = &self.grid[*node];
+        let next: Vec<Point> = if self.diagonal {
+            self.grid.neighbors8(*node).collect()
+        } else {
+            self.grid.neighbors4(*node).collect()
+        };
+        next.into_iter()
+            .filter_map(move |to| Some((to, (self.cost)(from, &self.grid[to])?)))
+    }
+}
+
+/// A route from the start to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    /// Every node on the route, start and goal included.
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+/// How much work a search did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Nodes taken off the frontier and expanded.
+    pub visited: usize,
+    /// Distinct nodes ever reached.
+    pub discovered: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult<N> {
+    pub path: Option<Path<N>>,
+    pub stats: Stats,
+}
+
+/// Nodes seen so far, with the best known cost and predecessor of each.
+struct Explored<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    parent: Vec<Option<usize>>,
+    cost: Vec<u64>,
+}
+
+impl<N: Clone + Eq + Hash> Explored<N> {
+    fn new(start: N) -> Self {
+        Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            parent: vec![None],
+            cost: vec![0],
+        }
+    }
+
+    /// Records a route to `node`, returning its index if it is new or cheaper than before.
+    fn relax(&mut self, node: N, parent: usize, cost: u64) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&id) if self.cost[id] <= cost => None,
+            Some(&id) => {
+                self.parent[id] = Some(parent);
+                self.cost[id] = cost;
+                Some(id)
+            }
+            None => {
+                let id = self.nodes.len();
+                self.index.insert(node.clone(), id);
+                self.nodes.push(node);
+                self.parent.push(Some(parent));
+                self.cost.push(cost);
+                Some(id)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N> {
+        let mut nodes = vec![self.nodes[goal].clone()];
+        let mut current = goal;
+        while let Some(parent) = self.parent[current] {
+            nodes.push(self.nodes[parent].clone());
+            current = parent;
+        }
+        nodes.reverse();
+        Path {
+            nodes,
+            cost: self.cost[goal],
+        }
+    }
+
+    fn finish(&self, goal: Option<usize>, visited: usize) -> SearchResult<N> {
+        SearchResult {
+            path: goal.map(|goal| self.path(goal)),
+            stats: Stats {
+                visited,
+                discovered: self.nodes.len(),
+            },
+        }
+    }
+}
+
+/// Breadth-first search, treating every edge as one step and ignoring its cost.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> SearchResult<G::Node> {
+    let mut explored = Explored::new(start);
+    let mut queue = VecDeque::from([0]);
+    let mut visited = 0;
+
+    while let Some(id) = queue.pop_front() {
+        visited += 1;
+        if is_goal(&explored.nodes[id]) {
+            return explored.finish(Some(id), visited);
+        }
+        let (node, steps) = (explored.nodes[id].clone(), explored.cost[id] + 1);
+        for (next, _) in graph.neighbors(&node) {
+            if !explored.index.contains_key(&next) {
+                queue.extend(explored.relax(next, id, steps));
+            }
+        }
+    }
+    explored.finish(None, visited)
+}
+
+/// Cheapest path by total edge cost.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> SearchResult<G::Node> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Cheapest path, guided by a `heuristic` that must never overestimate the remaining cost.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> SearchResult<G::Node> {
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut explored = Explored::new(start);
+    let mut visited = 0;
+
+    while let Some(Reverse((_, cost, id))) = frontier.pop() {
+        if cost > explored.cost[id] {
+            // A cheaper route to this node was already expanded.
+            continue;
+        }
+        visited += 1;
+        if is_goal(&explored.nodes[id]) {
+            return explored.finish(Some(id), visited);
+        }
+        let node = explored.nodes[id].clone();
+        for (next, step) in graph.neighbors(&node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = explored.relax(next, id, next_cost) {
+                frontier.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+    explored.finish(None, visited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E
+";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(|&tile| tile == 'S').unwrap();
+        let end = grid.find(|&tile| tile == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    fn open(_: &char, to: &char) -> Option<u64> {
+        (*to != '#').then_some(1)
+    }
+
+    #[test]
+    fn searches_agree_on_the_shortest_route() {
+        let (grid, start, end) = maze();
+        let graph = grid.graph(open);
+
+        let bfs = bfs(&graph, start, |&pos| pos == end);
+        let dijkstra = dijkstra(&graph, start, |&pos| pos == end);
+        let astar = astar(
+            &graph,
+            start,
+            |&pos| pos == end,
+            |&pos| pos.manhattan(end).into(),
+        );
+
+        for result in [&bfs, &dijkstra, &astar] {
+            let path = result.path.as_ref().unwrap();
+            assert_eq!(path.cost, 15);
+            assert_eq!(path.nodes.len(), 16);
+            assert_eq!((path.nodes[0], path.nodes[15]), (start, end));
+            assert!(path
+                .nodes
+                .windows(2)
+                .all(|step| step[0].manhattan(step[1]) == 1));
+        }
+        assert!(astar.stats.visited <= dijkstra.stats.visited);
+    }
+
+    #[test]
+    fn weighted_edges_prefer_the_cheaper_detour() {
+        // 0 -> 1 -> 3 costs 10, 0 -> 2 -> 3 costs 4 over more hops.
+        let graph = from_fn(|&node: &u32| match node {
+            0 => vec![(1, 5), (2, 1)],
+            1 => vec![(3, 5)],
+            2 => vec![(4, 1)],
+            4 => vec![(3, 2)],
+            _ => vec![],
+        });
+        let cheapest = dijkstra(&graph, 0, |&node| node == 3).path.unwrap();
+        assert_eq!(cheapest.nodes, vec![0, 2, 4, 3]);
+        assert_eq!(cheapest.cost, 4);
+
+        let fewest_hops = bfs(&graph, 0, |&node| node == 3).path.unwrap();
+        assert_eq!(fewest_hops.nodes, vec![0, 1, 3]);
+        assert_eq!(fewest_hops.cost, 2);
+    }
+
+    #[test]
+    fn unreachable_goals_report_the_explored_area() {
+        let (grid, start, _) = maze();
+        let walled = grid.graph(|_, to| (*to == '.').then_some(1));
+        let result = bfs(&walled, start, |&tile| tile == Point::new(100, 100));
+        assert_eq!(result.path, None);
+        assert_eq!(result.stats.visited, result.stats.discovered);
+        assert!(result.stats.visited > 1);
+    }
+}
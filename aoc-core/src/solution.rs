@@ -0,0 +1,137 @@
+use std::any::Any;
+use std::fmt::{self, Display};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::diagnostics::Diagnostics;
+use crate::render::Scene;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow!("Part must be 1 or 2, got {value}")),
+        }
+    }
+}
+
+/// A single day's puzzle: the input is parsed once and shared by both parts.
+pub trait Solution: Send + Sync {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed: Send + Sync + 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+
+    /// Parses in the mode `diagnostics` asks for; days without malformed-line handling just `parse`.
+    fn parse_with(&self, input: &str, _diagnostics: &mut Diagnostics) -> Result<Self::Parsed> {
+        self.parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Self::Answer1>;
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::Answer2>;
+
+    /// What a part looked at, for days that can draw it; most days can't.
+    fn scene(&self, _parsed: &Self::Parsed, _part: Part) -> Result<Option<Scene>> {
+        Ok(None)
+    }
+
+    /// Switches the parsed puzzle to a named alternative rule set, for days that have one.
+    fn variant(&self, _parsed: Self::Parsed, name: &str) -> Result<Self::Parsed> {
+        bail!("{} day {} has no variant '{name}'", Self::YEAR, Self::DAY)
+    }
+}
+
+/// Parsed input with its concrete type erased, as produced by [`DynSolution::parse`].
+pub struct ParsedInput(Box<dyn Any + Send + Sync>);
+
+/// Object-safe view of a [`Solution`] so days can be stored and dispatched uniformly.
+pub trait DynSolution: Send + Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<ParsedInput>;
+    fn parse_with(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<ParsedInput>;
+    fn solve(&self, parsed: &ParsedInput, part: Part) -> Result<String>;
+    fn scene(&self, parsed: &ParsedInput, part: Part) -> Result<Option<Scene>>;
+    fn variant(&self, parsed: ParsedInput, name: &str) -> Result<ParsedInput>;
+}
+
+fn downcast<S: Solution>(parsed: &ParsedInput) -> Result<&S::Parsed> {
+    parsed
+        .0
+        .downcast_ref::<S::Parsed>()
+        .ok_or_else(|| anyhow!("Parsed input does not belong to {} day {}", S::YEAR, S::DAY))
+}
+
+impl<S: Solution> DynSolution for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Ok(ParsedInput(Box::new(Solution::parse(self, input)?)))
+    }
+
+    fn parse_with(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<ParsedInput> {
+        Ok(ParsedInput(Box::new(Solution::parse_with(
+            self,
+            input,
+            diagnostics,
+        )?)))
+    }
+
+    fn solve(&self, parsed: &ParsedInput, part: Part) -> Result<String> {
+        let parsed = downcast::<S>(parsed)?;
+        match part {
+            Part::One => Ok(self.part1(parsed)?.to_string()),
+            Part::Two => Ok(self.part2(parsed)?.to_string()),
+        }
+    }
+
+    fn scene(&self, parsed: &ParsedInput, part: Part) -> Result<Option<Scene>> {
+        Solution::scene(self, downcast::<S>(parsed)?, part)
+    }
+
+    fn variant(&self, parsed: ParsedInput, name: &str) -> Result<ParsedInput> {
+        let parsed = parsed
+            .0
+            .downcast::<S::Parsed>()
+            .map_err(|_| anyhow!("Parsed input does not belong to {} day {}", S::YEAR, S::DAY))?;
+        Ok(ParsedInput(Box::new(Solution::variant(
+            self, *parsed, name,
+        )?)))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// Template cells that match any tile; `.` is left free to require an open floor tile.
+pub const WILDCARD: char = '?';
+
+/// One orientation of a pattern: glyphs at offsets from the top-left of its bounding box.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Stencil {
+    cells: Vec<(Point, char)>,
+}
+
+impl Stencil {
+    fn new(cells: impl IntoIterator<Item = (Point, char)>) -> Self {
+        let mut cells: Vec<(Point, char)> = cells.into_iter().collect();
+        let min_x = cells.iter().map(|(pos, _)| pos.x).min().unwrap_or(0);
+        let min_y = cells.iter().map(|(pos, _)| pos.y).min().unwrap_or(0);
+        for (pos, _) in &mut cells {
+            *pos -= Point::new(min_x, min_y);
+        }
+        cells.sort_by_key(|(pos, _)| pos.to_row_col());
+        Self { cells }
+    }
+
+    fn transformed(&self, transform: impl Fn(Point) -> Point) -> Self {
+        Self::new(
+            self.cells
+                .iter()
+                .map(|&(pos, glyph)| (transform(pos), glyph)),
+        )
+    }
+
+    fn fits(&self, grid: &Grid<char>, anchor: Point) -> bool {
+        self.cells
+            .iter()
+            .all(|&(offset, glyph)| grid.get(anchor + offset) == Some(&glyph))
+    }
+}
+
+/// Where a pattern was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// The top-left corner of the matching orientation's bounding box.
+    pub anchor: Point,
+    /// The grid cells covered by the pattern's non-wildcard glyphs.
+    pub cells: Vec<Point>,
+}
+
+/// A small 2D template, in one or more orientations, to search grids for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    variants: Vec<Stencil>,
+}
+
+impl Pattern {
+    /// A pattern drawn as rows of text, with [`WILDCARD`] cells matching anything.
+    pub fn new(template: &str) -> Self {
+        let cells = template.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, glyph)| glyph != WILDCARD)
+                .map(move |(x, glyph)| (Point::new(x as i32, y as i32), glyph))
+        });
+        Self {
+            variants: vec![Stencil::new(cells)],
+        }
+    }
+
+    /// `word` spelled in a straight line in any of the eight directions.
+    pub fn ray(word: &str) -> Self {
+        let along = |step: Point| {
+            Stencil::new(
+                word.chars()
+                    .enumerate()
+                    .map(|(i, glyph)| (step * i as i32, glyph)),
+            )
+        };
+        Self {
+            variants: vec![along(Point::new(1, 0)), along(Point::new(1, 1))],
+        }
+        .rotations()
+    }
+
+    /// Adds the quarter, half and three-quarter turns of every orientation.
+    pub fn rotations(self) -> Self {
+        self.with(|stencil| {
+            let quarter = stencil.transformed(|pos| Point::new(-pos.y, pos.x));
+            let half = quarter.transformed(|pos| Point::new(-pos.y, pos.x));
+            let three_quarters = half.transformed(|pos| Point::new(-pos.y, pos.x));
+            vec![quarter, half, three_quarters]
+        })
+    }
+
+    /// Adds the mirror image of every orientation.
+    pub fn reflections(self) -> Self {
+        self.with(|stencil| vec![stencil.transformed(|pos| Point::new(-pos.x, pos.y))])
+    }
+
+    fn with(mut self, extra: impl Fn(&Stencil) -> Vec<Stencil>) -> Self {
+        let added: Vec<Stencil> = self.variants.iter().flat_map(extra).collect();
+        for stencil in added {
+            // Symmetric templates map onto themselves; keep each shape once so no match doubles up.
+            if !self.variants.contains(&stencil) {
+                self.variants.push(stencil);
+            }
+        }
+        self
+    }
+
+    /// The number of distinct orientations.
+    pub fn orientations(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// Every placement of every orientation that fits `grid`.
+    pub fn matches(&self, grid: &Grid<char>) -> Vec<Match> {
+        let mut found = Vec::new();
+        if self.variants.iter().all(|stencil| stencil.cells.is_empty()) {
+            return found;
+        }
+        for anchor in grid.positions() {
+            for stencil in &self.variants {
+                if stencil.fits(grid, anchor) {
+                    found.push(Match {
+                        anchor,
+                        cells: stencil
+                            .cells
+                            .iter()
+                            .map(|&(offset, _)| anchor + offset)
+                            .collect(),
+                    });
+                }
+            }
+        }
+        found
+    }
+
+    /// The anchor of every match; an anchor repeats when several orientations fit there.
+    pub fn anchors(&self, grid: &Grid<char>) -> Vec<Point> {
+        self.matches(grid)
+            .into_iter()
+            .map(|found| found.anchor)
+            .collect()
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.matches(grid).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientations_are_deduplicated() {
+        assert_eq!(Pattern::ray("XMAS").orientations(), 8);
+        assert_eq!(Pattern::ray("ABA").orientations(), 4);
+        assert_eq!(Pattern::new("M?S\n?A?\nM?S").rotations().orientations(), 4);
+        assert_eq!(
+            Pattern::new("AB\nC?")
+                .rotations()
+                .reflections()
+                .orientations(),
+            8
+        );
+        assert_eq!(
+            Pattern::new("#").rotations().reflections().orientations(),
+            1
+        );
+    }
+
+    #[test]
+    fn wildcards_match_anything_and_anchors_are_top_left() {
+        let grid: Grid<char> = "MXS\nZAQ\nMYS\n".parse().unwrap();
+        let x_mas = Pattern::new("M?S\n?A?\nM?S").rotations();
+        let found = x_mas.matches(&grid);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].anchor, Point::new(0, 0));
+        assert_eq!(
+            found[0].cells,
+            vec![
+                Point::new(0, 0),
+                Point::new(2, 0),
+                Point::new(1, 1),
+                Point::new(0, 2),
+                Point::new(2, 2),
+            ]
+        );
+        // Both diagonals spell SAM and share a bounding box.
+        assert_eq!(
+            Pattern::ray("SAM").anchors(&grid),
+            vec![Point::new(0, 0); 2]
+        );
+        assert_eq!(Pattern::new("").count(&grid), 0);
+    }
+
+    #[test]
+    fn dots_are_floor_tiles_not_wildcards() {
+        let grid: Grid<char> = "#.#\n###\n".parse().unwrap();
+        assert_eq!(Pattern::new("#.#").anchors(&grid), vec![Point::new(0, 0)]);
+        assert_eq!(Pattern::new("#?#").count(&grid), 2);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use aoc_core::{input, Part};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Environment variable that overrides the location of the answers file.
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    fn slot(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Year -> day -> input hash -> known answers.
+type Entries = BTreeMap<u16, BTreeMap<u8, BTreeMap<String, Answers>>>;
+
+/// Known-good answers, stored as JSON and keyed by a hash of the input they belong to.
+pub struct AnswerStore {
+    path: PathBuf,
+    entries: Entries,
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`; a missing file is an empty store.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse answers file {}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => Entries::new(),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read answers file {}", path.display()))
+            }
+        };
+        Ok(Self { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, year: u16, day: u8, input_hash: &str, part: Part) -> Option<&str> {
+        self.entries
+            .get(&year)?
+            .get(&day)?
+            .get(input_hash)?
+            .get(part)
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, input_hash: &str, part: Part, answer: String) {
+        let answers = self
+            .entries
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default();
+        *answers.slot(part) = Some(answer);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(&self.entries)?;
+        fs::write(&self.path, contents + "\n")
+            .with_context(|| format!("Failed to write answers file {}", self.path.display()))
+    }
+}
+
+/// The default answers file, `answers.json` at the workspace root.
+pub fn default_answers_path() -> PathBuf {
+    input::workspace_dir().join("answers.json")
+}
+
+/// Hex-encoded SHA-256 of the input, ignoring trailing whitespace.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.trim_end().as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    fn empty_store(scratch: &ScratchDir) -> AnswerStore {
+        AnswerStore::load(scratch.join("answers.json")).unwrap()
+    }
+
+    #[test]
+    fn answers_survive_a_save_and_reload() {
+        let scratch = ScratchDir::new("answers");
+        let mut store = empty_store(&scratch);
+        assert_eq!(store.get(2024, 6, "abc", Part::One), None);
+
+        store.set(2024, 6, "abc", Part::One, "41".into());
+        store.set(2024, 6, "abc", Part::Two, "6".into());
+        store.set(2024, 6, "abc", Part::Two, "7".into());
+        store.save().unwrap();
+        let reloaded = AnswerStore::load(store.path()).unwrap();
+
+        assert_eq!(reloaded.get(2024, 6, "abc", Part::One), Some("41"));
+        assert_eq!(reloaded.get(2024, 6, "abc", Part::Two), Some("7"));
+        assert_eq!(reloaded.get(2024, 7, "abc", Part::One), None);
+    }
+
+    #[test]
+    fn a_corrupt_file_is_an_error_not_an_empty_store() {
+        let scratch = ScratchDir::new("answers");
+        let store = empty_store(&scratch);
+        fs::write(store.path(), "{ not json").unwrap();
+
+        assert!(AnswerStore::load(store.path()).is_err());
+    }
+
+    #[test]
+    fn answers_are_keyed_by_the_sha256_of_the_input() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(input_hash("abc\n\n"), input_hash("abc"));
+
+        let scratch = ScratchDir::new("answers");
+        let mut store = empty_store(&scratch);
+        store.set(2024, 1, &input_hash("1 2\n"), Part::One, "1".into());
+
+        assert_eq!(store.get(2024, 1, &input_hash("1 2"), Part::One), Some("1"));
+        assert_eq!(store.get(2024, 1, &input_hash("2 1\n"), Part::One), None);
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::input;
+use aoc_core::{DynSolution, Part};
+use clap::{ArgGroup, Args};
+use serde::{Deserialize, Serialize};
+
+use crate::registry;
+use crate::run::{parse_part, InputArgs};
+
+#[derive(Args)]
+// A single input file only makes sense for a single day.
+#[command(group(ArgGroup::new("one_input").arg("input").requires("day")))]
+pub struct BenchArgs {
+    /// Only benchmark this year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only benchmark this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// Only benchmark this part (1 or 2)
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+    /// Timed runs per part
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// Write the results as JSON to this file
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Compare against results previously written with --output
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Percentage slowdown of the median that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Timing statistics for one part, in nanoseconds.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub iterations: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+    pub min_ns: f64,
+    pub max_ns: f64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchResult {
+    fn from_samples(year: u16, day: u8, part: Part, samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[nanos.len() / 2 - 1] + nanos[nanos.len() / 2]) / 2.0
+        } else {
+            nanos[nanos.len() / 2]
+        };
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            year,
+            day,
+            part: part.number(),
+            iterations: nanos.len(),
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: variance.sqrt(),
+            min_ns: nanos[0],
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+
+    fn key(&self) -> (u16, u8, u8) {
+        (self.year, self.day, self.part)
+    }
+
+    /// Percentage change of the median against an earlier run; positive is slower.
+    fn change_from(&self, previous: &BenchResult) -> f64 {
+        (self.median_ns - previous.median_ns) / previous.median_ns * 100.0
+    }
+
+    fn regressed_from(&self, previous: &BenchResult, threshold: f64) -> bool {
+        self.change_from(previous) > threshold
+    }
+}
+
+fn nanos(ns: f64) -> Duration {
+    Duration::from_nanos(ns.round() as u64)
+}
+
+pub fn bench(args: BenchArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+            let report: BenchReport = serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse baseline {}", path.display()))?;
+            report
+                .results
+                .into_iter()
+                .map(|result| (result.key(), result))
+                .collect()
+        }
+        None => BTreeMap::new(),
+    };
+
+    let mut report = BenchReport::default();
+    let mut regressions = 0;
+    for solution in registry::select(args.year, args.day)? {
+        let source = args.input.source(solution.year(), solution.day());
+        let (year, day) = (solution.year(), solution.day());
+        let loaded = match source.load() {
+            Ok(loaded) => loaded,
+            Err(err) if input::is_missing(&err) => {
+                println!("{year} day {day}: skipped, missing input ({source})");
+                continue;
+            }
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read input for {year} day {day}"))
+            }
+        };
+        let input = loaded
+            .as_str()
+            .map_err(|err| err.in_file(&source))
+            .with_context(|| format!("Failed to read input for {year} day {day}"))?;
+
+        for &part in &parts {
+            let result = bench_part(solution, input, part, args.warmup, args.iterations)
+                .map_err(|err| source.locate(err))?;
+            let mut line = format!(
+                "{} day {} part {}: mean {:.2?}  median {:.2?}  stddev {:.2?}  ({} runs)",
+                result.year,
+                result.day,
+                result.part,
+                nanos(result.mean_ns),
+                nanos(result.median_ns),
+                nanos(result.stddev_ns),
+                result.iterations
+            );
+            if let Some(previous) = baseline.get(&result.key()) {
+                line += &format!("  {:+.1}% vs baseline", result.change_from(previous));
+                if result.regressed_from(previous, args.threshold) {
+                    line += "  REGRESSION";
+                    regressions += 1;
+                }
+            }
+            println!("{line}");
+            report.results.push(result);
+        }
+    }
+
+    if let Some(path) = &args.output {
+        fs::write(path, serde_json::to_string_pretty(&report)? + "\n")
+            .with_context(|| format!("Failed to write results to {}", path.display()))?;
+    }
+    if regressions > 0 {
+        bail!(
+            "{regressions} part(s) regressed by more than {}%",
+            args.threshold
+        );
+    }
+    Ok(())
+}
+
+fn bench_part(
+    solution: &dyn DynSolution,
+    input: &str,
+    part: Part,
+    warmup: usize,
+    iterations: u32,
+) -> Result<BenchResult> {
+    let (year, day) = (solution.year(), solution.day());
+    let parsed = solution.parse(input)?;
+    for _ in 0..warmup {
+        solution.solve(&parsed, part)?;
+    }
+
+    let mut samples = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        solution
+            .solve(&parsed, part)
+            .with_context(|| format!("{year} day {day} part {part} failed"))?;
+        samples.push(start.elapsed());
+    }
+    Ok(BenchResult::from_samples(year, day, part, &samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(millis: &[u64]) -> BenchResult {
+        let samples: Vec<_> = millis.iter().copied().map(Duration::from_millis).collect();
+        BenchResult::from_samples(2024, 6, Part::Two, &samples)
+    }
+
+    #[test]
+    fn statistics_come_from_the_sorted_samples() {
+        let odd = result(&[9, 2, 4]);
+        assert_eq!(odd.iterations, 3);
+        assert_eq!(odd.mean_ns, 5e6);
+        assert_eq!(odd.median_ns, 4e6);
+        assert_eq!(odd.stddev_ns, 13e12_f64.sqrt());
+        assert_eq!((odd.min_ns, odd.max_ns), (2e6, 9e6));
+
+        let even = result(&[8, 2, 4, 6]);
+        assert_eq!(even.mean_ns, 5e6);
+        assert_eq!(even.median_ns, 5e6);
+        assert_eq!(even.stddev_ns, (20e12_f64 / 3.0).sqrt());
+
+        let single = result(&[7]);
+        assert_eq!((single.median_ns, single.stddev_ns), (7e6, 0.0));
+    }
+
+    #[test]
+    fn only_a_slowdown_past_the_threshold_is_a_regression() {
+        let baseline = result(&[10]);
+
+        assert!(result(&[12]).regressed_from(&baseline, 10.0));
+        assert!(!result(&[11]).regressed_from(&baseline, 10.0));
+        assert!(!result(&[5]).regressed_from(&baseline, 10.0));
+        assert_eq!(result(&[5]).change_from(&baseline), -50.0);
+    }
+}
@@ -0,0 +1,244 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_core::Part;
+use ureq::Agent;
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable that points the client at another server, e.g. a local stand-in.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "aoc-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/nasissa97/advent_of_code)"
+);
+
+/// Minimal client for the adventofcode.com endpoints the runner needs.
+pub struct AocClient {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self {
+            agent,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// Builds a client from `$AOC_BASE_URL` and the configured session token.
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(base_url, session_token()?))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", self.cookie())
+            .call()
+            .with_context(|| format!("Request to {url} failed"))?;
+
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        match status {
+            200 => Ok(body),
+            404 => bail!("The input for {year} day {day} is not available yet"),
+            400 | 401 | 403 => bail!(
+                "Server rejected the session token ({status}): {}",
+                body.trim()
+            ),
+            _ => bail!("Unexpected response {status} from {url}: {}", body.trim()),
+        }
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .with_context(|| format!("Request to {url} failed"))?;
+
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        if status != 200 {
+            bail!("Unexpected response {status} from {url}: {}", body.trim());
+        }
+        Ok(parse_verdict(&body))
+    }
+}
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too soon after the previous attempt.
+    RateLimited(Duration),
+    /// The part has already been solved, or part 1 is still open.
+    WrongLevel,
+    Unrecognised(String),
+}
+
+/// Reads the verdict out of the page returned for an answer submission.
+pub fn parse_verdict(page: &str) -> Verdict {
+    let text = page_text(page);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unrecognised(text.trim().to_string())
+    }
+}
+
+/// The text inside the page's `<article>`, with tags stripped.
+fn page_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let mut text = String::with_capacity(article.len());
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Parses the "you have 4m 3s left to wait" part of a rate-limit message.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("have ")? + "have ".len();
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// The session token from `$AOC_SESSION`, or from the `aoc/session` file in the config directory.
+pub fn session_token() -> Result<String> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+
+    let path = config_dir()
+        .map(|dir| dir.join("aoc").join("session"))
+        .ok_or_else(|| anyhow!("Set {SESSION_ENV} to your adventofcode.com session cookie"))?;
+    let token = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No session token: set {SESSION_ENV} or write it to {}",
+            path.display()
+        )
+    })?;
+    Ok(token.trim().to_string())
+}
+
+fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn recognises_answer_pages() {
+        let cases = [
+            (
+                "That's the right answer! You are <em>one gold star</em> closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Verdict::Incorrect,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::WrongLevel,
+            ),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(parse_verdict(&page(message)), verdict, "{message}");
+        }
+    }
+
+    #[test]
+    fn parses_rate_limit_wait() {
+        let verdict = parse_verdict(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 4m 3s left to wait.",
+        ));
+        assert_eq!(verdict, Verdict::RateLimited(Duration::from_secs(243)));
+
+        let verdict = parse_verdict(&page(
+            "You gave an answer too recently.  You have 34s left to wait.",
+        ));
+        assert_eq!(verdict, Verdict::RateLimited(Duration::from_secs(34)));
+    }
+}
@@ -0,0 +1,376 @@
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use aoc_core::{Color, Layer, Scene};
+
+use crate::font;
+
+const CELL: usize = 12;
+const MARGIN: usize = 8;
+const SCALE: usize = 2;
+const LEGEND_ROW: usize = 16;
+
+const PAPER: [u8; 3] = [0xff, 0xff, 0xff];
+const FLOOR: [u8; 3] = [0xf2, 0xf2, 0xf2];
+const WALL: [u8; 3] = [0xc8, 0xc8, 0xc8];
+const INK: [u8; 3] = [0x22, 0x22, 0x22];
+const FADED_INK: [u8; 3] = [0xb4, 0xb4, 0xb4];
+
+/// Tiles that are drawn as empty floor rather than as a glyph.
+fn is_floor(tile: char) -> bool {
+    tile == '.'
+}
+
+fn legend_label(layer: &Layer, size: usize) -> String {
+    format!("{} ({size})", layer.name)
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Where each part of the picture goes, shared by both output formats.
+struct Layout {
+    width: usize,
+    height: usize,
+    legend_top: usize,
+}
+
+impl Layout {
+    fn new(scene: &Scene, label_width: impl Fn(&str) -> usize) -> Self {
+        let sizes = scene.layer_sizes();
+        let legend_width = scene
+            .layers
+            .iter()
+            .zip(&sizes)
+            .map(|(layer, &size)| CELL + 6 + label_width(&legend_label(layer, size)))
+            .max()
+            .unwrap_or(0);
+        let grid_width = scene.grid.width() * CELL;
+        let legend_top = MARGIN + scene.grid.height() * CELL + MARGIN;
+        Self {
+            width: grid_width.max(legend_width) + 2 * MARGIN,
+            height: legend_top + scene.layers.len() * LEGEND_ROW + MARGIN,
+            legend_top,
+        }
+    }
+}
+
+/// Draws `scene` as an SVG document: one square per cell, tiles as text, legend underneath.
+pub fn svg(scene: &Scene) -> String {
+    // Monospace text at 12px is roughly 7px per character.
+    let layout = Layout::new(scene, |label| label.len() * 7);
+    let coverage = scene.coverage();
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="monospace" font-size="12">"#,
+        w = layout.width,
+        h = layout.height,
+    );
+    let _ = writeln!(
+        out,
+        r#"<rect width="100%" height="100%" fill="{}"/>"#,
+        hex(PAPER)
+    );
+
+    for (pos, &tile) in scene.grid.iter() {
+        let (x, y) = (
+            MARGIN + pos.x as usize * CELL,
+            MARGIN + pos.y as usize * CELL,
+        );
+        let layer = coverage[pos];
+        let fill = match layer {
+            Some(layer) => layer.color.rgb(),
+            None if is_floor(tile) || scene.fade_unmarked => FLOOR,
+            None => WALL,
+        };
+        let _ = writeln!(
+            out,
+            r#"<rect x="{x}" y="{y}" width="{c}" height="{c}" fill="{}"/>"#,
+            hex(fill),
+            c = CELL - 1,
+        );
+        if !is_floor(tile) {
+            let ink = if layer.is_none() && scene.fade_unmarked {
+                FADED_INK
+            } else {
+                INK
+            };
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}" text-anchor="middle" fill="{}">{}</text>"#,
+                x + CELL / 2,
+                y + CELL - 2,
+                hex(ink),
+                escape(&tile.to_string()),
+            );
+        }
+    }
+
+    for (index, (layer, size)) in scene.layers.iter().zip(scene.layer_sizes()).enumerate() {
+        let y = layout.legend_top + index * LEGEND_ROW;
+        let _ = writeln!(
+            out,
+            r#"<rect x="{MARGIN}" y="{y}" width="{CELL}" height="{CELL}" fill="{}"/>"#,
+            hex(layer.color.rgb()),
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}" fill="{}">{}</text>"#,
+            MARGIN + CELL + 6,
+            y + CELL - 2,
+            hex(INK),
+            escape(&legend_label(layer, size)),
+        );
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// An RGB pixel buffer.
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: [u8; 3]) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: [u8; 3]) {
+        for row in y..(y + height).min(self.height) {
+            for col in x..(x + width).min(self.width) {
+                self.pixels[row * self.width + col] = color;
+            }
+        }
+    }
+
+    /// Writes `text` with the built-in pixel font, each font pixel `scale` pixels wide.
+    pub fn text(&mut self, x: usize, y: usize, text: &str, scale: usize, color: [u8; 3]) {
+        for (index, c) in text.chars().enumerate() {
+            let left = x + index * (font::WIDTH + 1) * scale;
+            for (row, bits) in font::glyph(c).into_iter().enumerate() {
+                for col in 0..font::WIDTH {
+                    if bits & (1 << (font::WIDTH - 1 - col)) != 0 {
+                        self.fill_rect(left + col * scale, y + row * scale, scale, scale, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// A copy grown to at least `width` x `height`, padded with paper.
+    pub fn padded(&self, width: usize, height: usize) -> Canvas {
+        let mut padded = Canvas::new(width.max(self.width), height.max(self.height), PAPER);
+        for (y, row) in self.pixels.chunks(self.width).enumerate() {
+            let start = y * padded.width;
+            padded.pixels[start..start + self.width].copy_from_slice(row);
+        }
+        padded
+    }
+
+    pub fn text_width(text: &str, scale: usize) -> usize {
+        text.chars().count() * (font::WIDTH + 1) * scale
+    }
+
+    pub fn write_png(&self, out: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(self.pixels.as_flattened())?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Draws `scene` onto a canvas with the same layout as [`svg`].
+pub fn raster(scene: &Scene) -> Canvas {
+    let layout = Layout::new(scene, |label| Canvas::text_width(label, SCALE));
+    let coverage = scene.coverage();
+    let mut canvas = Canvas::new(layout.width, layout.height, PAPER);
+    let glyph_x = (CELL - font::WIDTH * SCALE) / 2;
+    let glyph_y = (CELL - font::HEIGHT * SCALE) / 2;
+
+    for (pos, &tile) in scene.grid.iter() {
+        let (x, y) = (
+            MARGIN + pos.x as usize * CELL,
+            MARGIN + pos.y as usize * CELL,
+        );
+        let layer = coverage[pos];
+        let fill = match layer {
+            Some(layer) => layer.color.rgb(),
+            None if is_floor(tile) || scene.fade_unmarked => FLOOR,
+            None => WALL,
+        };
+        canvas.fill_rect(x, y, CELL - 1, CELL - 1, fill);
+        if !is_floor(tile) {
+            let ink = if layer.is_none() && scene.fade_unmarked {
+                FADED_INK
+            } else {
+                INK
+            };
+            canvas.text(x + glyph_x, y + glyph_y, &tile.to_string(), SCALE, ink);
+        }
+    }
+
+    for (index, (layer, size)) in scene.layers.iter().zip(scene.layer_sizes()).enumerate() {
+        let y = layout.legend_top + index * LEGEND_ROW;
+        canvas.fill_rect(MARGIN, y, CELL, CELL, layer.color.rgb());
+        canvas.text(
+            MARGIN + CELL + 6,
+            y + glyph_y,
+            &legend_label(layer, size),
+            SCALE,
+            INK,
+        );
+    }
+    canvas
+}
+
+/// Every colour a rasterised scene can contain, which doubles as the GIF palette.
+fn palette() -> Vec<[u8; 3]> {
+    let mut palette = vec![PAPER, FLOOR, WALL, INK, FADED_INK];
+    palette.extend(Color::PALETTE.map(Color::rgb));
+    palette
+}
+
+/// Encodes `frames` as a looping GIF, padding each one to `width` x `height`.
+pub fn write_gif(
+    out: impl Write,
+    width: usize,
+    height: usize,
+    delay: Duration,
+    frames: impl IntoIterator<Item = Canvas>,
+) -> Result<()> {
+    let palette = palette();
+    let (width, height) = (u16::try_from(width)?, u16::try_from(height)?);
+    let mut encoder = gif::Encoder::new(out, width, height, palette.as_flattened())?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for canvas in frames {
+        let canvas = canvas.padded(width.into(), height.into());
+        let indices = canvas
+            .pixels
+            .iter()
+            .map(|pixel| palette.iter().position(|color| color == pixel).unwrap_or(0) as u8)
+            .collect::<Vec<u8>>();
+        let frame = gif::Frame {
+            width: canvas.width as u16,
+            height: canvas.height as u16,
+            delay: (delay.as_millis() / 10).min(u16::MAX.into()) as u16,
+            buffer: indices.into(),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+pub fn write_svg(scene: &Scene, path: &Path) -> Result<()> {
+    std::fs::write(path, svg(scene)).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+pub fn write_png(scene: &Scene, path: &Path) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    raster(scene).write_png(BufWriter::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Point;
+
+    use super::*;
+
+    fn scene() -> Scene {
+        Scene::new("..#\n.^.\n".parse().unwrap())
+            .layer("path & more", Color::Cyan, Some('X'), [Point::new(0, 0)])
+            .layer("block", Color::Red, Some('O'), [Point::new(2, 1)])
+    }
+
+    #[test]
+    fn svg_has_a_cell_per_tile_and_a_legend() {
+        let svg = svg(&scene());
+        // Background, six cells and two legend swatches.
+        assert_eq!(svg.matches("<rect").count(), 9);
+        assert!(svg.contains(&hex(Color::Cyan.rgb())));
+        assert!(svg.contains(">path &amp; more (1)</text>"));
+        assert!(svg.contains(">block (1)</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn png_round_trips_through_the_decoder() {
+        let canvas = raster(&scene());
+        let mut bytes = Vec::new();
+        canvas.write_png(&mut bytes).unwrap();
+
+        let decoder = png::Decoder::new(bytes.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut decoded = vec![0; reader.output_buffer_size()];
+        let frame = reader.next_frame(&mut decoded).unwrap();
+        assert_eq!(
+            (frame.width as usize, frame.height as usize),
+            (canvas.width, canvas.height)
+        );
+        assert_eq!(frame.color_type, png::ColorType::Rgb);
+        assert_eq!(
+            decoded[..frame.buffer_size()],
+            *canvas.pixels.as_flattened()
+        );
+        let pixel = |x: usize, y: usize| {
+            let at = (y * canvas.width + x) * 3;
+            [decoded[at], decoded[at + 1], decoded[at + 2]]
+        };
+        assert_eq!(pixel(MARGIN, MARGIN), Color::Cyan.rgb());
+        assert_eq!(pixel(MARGIN + 2 * CELL, MARGIN + CELL), Color::Red.rgb());
+        assert_eq!(pixel(MARGIN + CELL, MARGIN), FLOOR);
+    }
+
+    #[test]
+    fn gif_frames_are_padded_to_the_screen() {
+        let small = raster(&Scene::new("..\n".parse().unwrap()));
+        let large = raster(&scene());
+        let mut bytes = Vec::new();
+        write_gif(
+            &mut bytes,
+            large.width,
+            large.height,
+            Duration::from_millis(100),
+            [small, large],
+        )
+        .unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(bytes.as_slice()).unwrap();
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 10);
+            assert_eq!(
+                frame.buffer.len(),
+                decoder.width() as usize * decoder.height() as usize
+            );
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+    }
+}
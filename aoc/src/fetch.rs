@@ -0,0 +1,125 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::input::{self, InputSource};
+use clap::Args;
+
+use crate::client::AocClient;
+use crate::registry;
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Puzzle year, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day
+    #[arg(long)]
+    day: u8,
+    /// Directory holding conventional inputs such as `2024/06.txt`
+    #[arg(long, env = input::INPUTS_DIR_ENV)]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FetchStatus {
+    Cached,
+    Downloaded,
+}
+
+pub fn fetch(args: FetchArgs) -> Result<()> {
+    let year = args
+        .year
+        .or_else(registry::latest_year)
+        .ok_or_else(|| anyhow!("No solutions are registered, pass --year"))?;
+    let inputs_dir = args.inputs_dir.unwrap_or_else(input::default_inputs_dir);
+    let InputSource::File(path) = InputSource::conventional(&inputs_dir, year, args.day) else {
+        unreachable!("conventional inputs are always files");
+    };
+
+    // Only build the client, and so require a session, when something needs downloading.
+    if path.exists() {
+        println!(
+            "{year} day {}: already cached at {}",
+            args.day,
+            path.display()
+        );
+        return Ok(());
+    }
+    let client = AocClient::from_env()?;
+    fetch_input(&client, &path, year, args.day)?;
+    println!("{year} day {}: saved to {}", args.day, path.display());
+    Ok(())
+}
+
+/// Downloads an input into `path` unless it is already cached there.
+pub fn fetch_input(client: &AocClient, path: &Path, year: u16, day: u8) -> Result<FetchStatus> {
+    if path.exists() {
+        return Ok(FetchStatus::Cached);
+    }
+
+    let input = client.fetch_input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    // Write to a temporary file first so an interrupted download never looks cached.
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).with_context(|| format!("Failed to write {}", partial.display()))?;
+    fs::rename(&partial, path).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(FetchStatus::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use crate::scratch::ScratchDir;
+
+    #[test]
+    fn downloads_with_session_cookie_and_caches() {
+        let server = MockServer::start(vec![(200, "3   4\n4   3\n".to_string())]);
+        let client = AocClient::new(server.url(), "secret");
+        let scratch = ScratchDir::new("fetch");
+        let path = scratch.join("2024").join("01.txt");
+
+        let status = fetch_input(&client, &path, 2024, 1).unwrap();
+
+        assert_eq!(status, FetchStatus::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("cookie: session=secret"));
+    }
+
+    #[test]
+    fn never_downloads_a_cached_day() {
+        let server = MockServer::start(vec![]);
+        let client = AocClient::new(server.url(), "secret");
+        let scratch = ScratchDir::new("fetch");
+        let path = scratch.join("2024").join("07.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "190: 10 19\n").unwrap();
+
+        let status = fetch_input(&client, &path, 2024, 7).unwrap();
+
+        assert_eq!(status, FetchStatus::Cached);
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn reports_locked_days_without_caching() {
+        let server = MockServer::start(vec![(404, "Not Found".to_string())]);
+        let client = AocClient::new(server.url(), "secret");
+        let scratch = ScratchDir::new("fetch");
+        let path = scratch.join("2024").join("25.txt");
+
+        let err = fetch_input(&client, &path, 2024, 25).unwrap_err();
+
+        assert!(err.to_string().contains("not available yet"));
+        assert!(!path.exists());
+    }
+}
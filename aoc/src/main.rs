@@ -0,0 +1,86 @@
+mod answers;
+mod bench;
+mod client;
+mod export;
+mod fetch;
+mod font;
+#[cfg(test)]
+mod mock_server;
+mod registry;
+mod replay;
+mod run;
+mod scaffold;
+#[cfg(test)]
+mod scratch;
+mod show;
+mod submit;
+mod verify;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every registered day with --all
+    Run(run::RunArgs),
+    /// Check every solution against the stored answers for its input
+    Verify(verify::VerifyArgs),
+    /// Time each part over repeated runs and compare against a baseline
+    Bench(bench::BenchArgs),
+    /// Download a puzzle input into the local inputs cache
+    Fetch(fetch::FetchArgs),
+    /// Submit an answer, refusing ones the server already rejected
+    Submit(submit::SubmitArgs),
+    /// Draw a day's grid with the cells each part looked at highlighted
+    Show(show::ShowArgs),
+    /// Animate the 2024 day 6 guard's patrol, optionally with an extra obstruction
+    Replay(replay::ReplayArgs),
+    /// Generate the crate for a new day and register it with the runner
+    New(scaffold::NewArgs),
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run::run(args),
+        Command::Verify(args) => verify::verify(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::Fetch(args) => fetch::fetch(args),
+        Command::Submit(args) => submit::submit(args),
+        Command::Show(args) => show::show(args),
+        Command::Replay(args) => replay::replay(args),
+        Command::New(args) => scaffold::new(args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(["aoc"].iter().chain(args))
+    }
+
+    #[test]
+    fn every_subcommand_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn an_input_file_needs_a_single_day() {
+        assert!(parse(&["run", "--day", "6", "--input", "6.txt"]).is_ok());
+        assert!(parse(&["run", "--all", "--input", "6.txt"]).is_err());
+        assert!(parse(&["verify", "--input", "6.txt"]).is_err());
+        assert!(parse(&["bench", "--day", "6", "--input", "6.txt"]).is_ok());
+        assert!(parse(&["replay", "--input", "6.txt"]).is_ok());
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::{anyhow, Result};
+use aoc_core::DynSolution;
+
+/// Every solution the runner knows about, in year/day order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.year() == year && solution.day() == day)
+}
+
+pub fn latest_year() -> Option<u16> {
+    SOLUTIONS.iter().map(|solution| solution.year()).max()
+}
+
+/// The solutions matching a year and day filter; a day without a year means the latest year.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static dyn DynSolution>> {
+    match day {
+        Some(day) => {
+            let year = year
+                .or_else(latest_year)
+                .ok_or_else(|| anyhow!("No solutions are registered"))?;
+            let solution = find(year, day)
+                .ok_or_else(|| anyhow!("No solution registered for {year} day {day}"))?;
+            Ok(vec![solution])
+        }
+        None => Ok(SOLUTIONS
+            .iter()
+            .copied()
+            .filter(|solution| year.is_none_or(|year| solution.year() == year))
+            .collect()),
+    }
+}
@@ -0,0 +1,157 @@
+use std::fs::File;
+use std::io::{BufWriter, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_core::{render, Point, Solution};
+use clap::Args;
+use day6::{Day6, Lab, Patrol};
+
+use crate::export;
+use crate::run::InputArgs;
+use crate::show::ColorChoice;
+
+#[derive(Args)]
+pub struct ReplayArgs {
+    /// Replay the walk with the Nth loop-causing obstruction (counting from 1, in reading order)
+    #[arg(long)]
+    obstruction: Option<usize>,
+    /// Write the replay to this GIF file instead of printing terminal frames
+    #[arg(long)]
+    gif: Option<PathBuf>,
+    /// Roughly how many frames to draw; long walks skip steps to fit
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    frames: u32,
+    /// Time between frames, in milliseconds
+    #[arg(long, default_value_t = 100)]
+    delay: u64,
+    /// Let the guard wrap around at the edges instead of leaving the lab
+    #[arg(long)]
+    wrap: bool,
+    /// When to colour terminal frames
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// The steps to draw: evenly spaced, always ending on the last one.
+fn sampled_steps(total: usize, frames: usize) -> Vec<usize> {
+    let stride = total.div_ceil(frames).max(1);
+    let mut steps: Vec<usize> = (0..total).step_by(stride).collect();
+    if steps.last() != Some(&(total - 1)) {
+        steps.push(total - 1);
+    }
+    steps
+}
+
+pub fn replay(args: ReplayArgs) -> Result<()> {
+    let source = args.input.source(Day6::YEAR, Day6::DAY);
+    let input = source
+        .load()
+        .with_context(|| format!("Failed to read input for {} day {}", Day6::YEAR, Day6::DAY))?;
+    let text = input.as_str().map_err(|err| err.in_file(&source))?;
+    let mut lab = Day6.parse(text).map_err(|err| source.locate(err))?;
+    if args.wrap {
+        lab = lab.wrapping();
+    }
+
+    let obstruction = match args.obstruction {
+        None => None,
+        Some(0) => bail!("Obstructions are counted from 1"),
+        Some(n) => {
+            let candidates = day6::obstruction_candidates(&lab)?;
+            let candidate = *candidates
+                .get(n - 1)
+                .ok_or_else(|| anyhow!("Only {} obstructions trap the guard", candidates.len()))?;
+            println!("Obstruction {n} of {} at {candidate}", candidates.len());
+            Some(candidate)
+        }
+    };
+
+    let patrol = day6::record_patrol(&lab, obstruction)?;
+    let steps = sampled_steps(patrol.steps.len(), args.frames as usize);
+    let delay = Duration::from_millis(args.delay);
+    match &args.gif {
+        Some(path) => write_gif(&lab, &patrol, obstruction, &steps, delay, path),
+        None => play(
+            &lab,
+            &patrol,
+            obstruction,
+            &steps,
+            delay,
+            args.color.enabled(),
+        ),
+    }
+}
+
+fn write_gif(
+    lab: &Lab,
+    patrol: &Patrol,
+    obstruction: Option<Point>,
+    steps: &[usize],
+    delay: Duration,
+    path: &Path,
+) -> Result<()> {
+    // The last frame has the longest legend, so it sets the size of the animation.
+    let last = export::raster(&day6::patrol_frame(lab, patrol, usize::MAX, obstruction)?);
+    let frames = steps
+        .iter()
+        .map(|&step| {
+            day6::patrol_frame(lab, patrol, step, obstruction).map(|scene| export::raster(&scene))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let file =
+        File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    export::write_gif(BufWriter::new(file), last.width, last.height, delay, frames)?;
+    println!("Wrote {} frames to {}", steps.len(), path.display());
+    Ok(())
+}
+
+fn play(
+    lab: &Lab,
+    patrol: &Patrol,
+    obstruction: Option<Point>,
+    steps: &[usize],
+    delay: Duration,
+    color: bool,
+) -> Result<()> {
+    let animate = std::io::stdout().is_terminal();
+    for &step in steps {
+        let scene = day6::patrol_frame(lab, patrol, step, obstruction)?;
+        if animate {
+            // Move the cursor home and clear the screen before each frame.
+            print!("\x1b[H\x1b[2J");
+        }
+        println!("Step {} of {}", step + 1, patrol.steps.len());
+        print!("{}", render::render_terminal(&scene, color));
+        if animate {
+            thread::sleep(delay);
+        } else {
+            println!();
+        }
+    }
+    let ending = if patrol.looped {
+        "got stuck in a loop"
+    } else {
+        "left the map"
+    };
+    println!("The guard {ending} after {} steps", patrol.steps.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_end_on_the_last_step() {
+        assert_eq!(sampled_steps(10, 4), vec![0, 3, 6, 9]);
+        assert_eq!(sampled_steps(10, 3), vec![0, 4, 8, 9]);
+        assert_eq!(sampled_steps(3, 100), vec![0, 1, 2]);
+        assert_eq!(sampled_steps(1, 1), vec![0]);
+    }
+}
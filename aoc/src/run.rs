@@ -0,0 +1,269 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::input::{self, InputSource};
+use aoc_core::{Diagnostics, DynSolution, Part, Skipped, Strictness};
+use clap::Args;
+
+use crate::registry;
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Puzzle year, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Only run this part (1 or 2)
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+    /// Run every registered day and print a summary table
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+    /// Skip malformed input lines instead of failing, and list them at the end
+    #[arg(long)]
+    lenient: bool,
+    /// Solve a day's alternative rule set, such as `wrap` for 2024 day 6
+    #[arg(long, requires = "day")]
+    variant: Option<String>,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+pub struct InputArgs {
+    /// Read the input from this file, or from stdin with `-`
+    #[arg(long)]
+    input: Option<InputSource>,
+    /// Directory holding conventional inputs such as `2024/06.txt`
+    #[arg(long, env = input::INPUTS_DIR_ENV)]
+    inputs_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    pub fn source(&self, year: u16, day: u8) -> InputSource {
+        match &self.input {
+            Some(source) => source.clone(),
+            None => {
+                let inputs_dir = self
+                    .inputs_dir
+                    .clone()
+                    .unwrap_or_else(input::default_inputs_dir);
+                InputSource::conventional(&inputs_dir, year, day)
+            }
+        }
+    }
+}
+
+pub fn parse_part(value: &str) -> Result<Part> {
+    Part::try_from(value.parse::<u8>()?)
+}
+
+/// The result of running one part of one day.
+pub struct PartOutcome {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub elapsed: Duration,
+}
+
+/// The result of parsing and running the selected parts of one day.
+pub struct DayOutcome {
+    pub year: u16,
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub result: Result<Vec<PartOutcome>>,
+    /// Malformed lines a lenient parse stepped over.
+    pub skipped: Vec<Skipped>,
+}
+
+impl DayOutcome {
+    fn failed(&self) -> bool {
+        match &self.result {
+            Ok(parts) => parts.iter().any(|outcome| outcome.answer.is_err()),
+            Err(_) => true,
+        }
+    }
+}
+
+pub fn run(args: RunArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let strictness = match args.lenient {
+        true => Strictness::Lenient,
+        false => Strictness::Strict,
+    };
+
+    if args.all {
+        let outcomes: Vec<DayOutcome> = registry::select(args.year, None)?
+            .into_iter()
+            .map(|solution| {
+                let source = args.input.source(solution.year(), solution.day());
+                run_day(solution, &source, &parts, strictness, None)
+            })
+            .collect();
+        print_summary(&outcomes, &parts);
+        outcomes.iter().for_each(report_skipped);
+        let failures = outcomes.iter().filter(|outcome| outcome.failed()).count();
+        if failures > 0 {
+            bail!("{failures} day(s) failed");
+        }
+        return Ok(());
+    }
+
+    let solution = registry::select(args.year, args.day)?[0];
+    let (year, day) = (solution.year(), solution.day());
+    let source = args.input.source(year, day);
+    let variant = args.variant.as_deref();
+    let mut outcome = run_day(solution, &source, &parts, strictness, variant);
+    let result = std::mem::replace(&mut outcome.result, Ok(Vec::new()));
+    let printed = result.and_then(|parts| {
+        for part in parts {
+            let answer = part
+                .answer
+                .with_context(|| format!("{year} day {day} part {} failed", part.part))?;
+            println!(
+                "{year} day {day} part {}: {answer} ({:.2?})",
+                part.part, part.elapsed
+            );
+        }
+        Ok(())
+    });
+    report_skipped(&outcome);
+    printed
+}
+
+/// Lists the lines a lenient run skipped, on stderr so answers stay clean.
+fn report_skipped(outcome: &DayOutcome) {
+    if outcome.skipped.is_empty() {
+        return;
+    }
+    eprintln!(
+        "{} day {}: skipped {} malformed line(s)",
+        outcome.year,
+        outcome.day,
+        outcome.skipped.len()
+    );
+    for skipped in &outcome.skipped {
+        eprintln!("  {} ({:?})", skipped.reason, skipped.text);
+    }
+}
+
+pub fn run_day(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    parts: &[Part],
+    strictness: Strictness,
+    variant: Option<&str>,
+) -> DayOutcome {
+    let outcome = source.load().and_then(|input| {
+        let text = input.as_str().map_err(|err| err.in_file(source))?;
+        let mut outcome = run_input(solution, text, parts, strictness, variant);
+        outcome.result = outcome.result.map_err(|err| source.locate(err));
+        Ok(outcome)
+    });
+    outcome.unwrap_or_else(|err| DayOutcome {
+        year: solution.year(),
+        day: solution.day(),
+        parse_elapsed: Duration::ZERO,
+        result: Err(err),
+        skipped: Vec::new(),
+    })
+}
+
+pub fn run_input(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    strictness: Strictness,
+    variant: Option<&str>,
+) -> DayOutcome {
+    let mut diagnostics = Diagnostics::new(strictness);
+    let start = Instant::now();
+    let parsed = solution
+        .parse_with(input, &mut diagnostics)
+        .and_then(|parsed| match variant {
+            Some(name) => solution.variant(parsed, name),
+            None => Ok(parsed),
+        });
+    let parse_elapsed = start.elapsed();
+
+    let result = parsed.map(|parsed| {
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = solution.solve(&parsed, part);
+                PartOutcome {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect()
+    });
+
+    DayOutcome {
+        year: solution.year(),
+        day: solution.day(),
+        parse_elapsed,
+        result,
+        skipped: diagnostics.into_skipped(),
+    }
+}
+
+fn print_summary(outcomes: &[DayOutcome], parts: &[Part]) {
+    let mut header = vec!["Year".to_string(), "Day".to_string(), "Parse".to_string()];
+    for part in parts {
+        header.push(format!("Part {part}"));
+        header.push("Time".to_string());
+    }
+
+    let mut rows = vec![header];
+    let mut total = Duration::ZERO;
+    for outcome in outcomes {
+        let mut row = vec![outcome.year.to_string(), outcome.day.to_string()];
+        match &outcome.result {
+            Ok(part_outcomes) => {
+                total += outcome.parse_elapsed;
+                row.push(format!("{:.2?}", outcome.parse_elapsed));
+                for part in part_outcomes {
+                    total += part.elapsed;
+                    row.push(match &part.answer {
+                        Ok(answer) => answer.clone(),
+                        Err(err) => format!("error: {err}"),
+                    });
+                    row.push(format!("{:.2?}", part.elapsed));
+                }
+            }
+            Err(err) => row.push(format!("error: {err:#}")),
+        }
+        rows.push(row);
+    }
+
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| {
+            // An error message spans the remaining columns, so it doesn't widen them.
+            rows.iter()
+                .filter(|row| row.len() == columns || col + 1 < row.len())
+                .filter_map(|row| row.get(col))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in &rows {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(col, cell)| format!("{cell:<width$}", width = widths[col]))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+    println!("Total time: {total:.2?}");
+}
@@ -0,0 +1,234 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_core::input;
+use clap::Args;
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Puzzle year
+    year: u16,
+    /// Puzzle day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+pub fn new(args: NewArgs) -> Result<()> {
+    let root = input::workspace_dir();
+    let dir = scaffold(&root, args.year, args.day)?;
+    println!(
+        "Created {}; add the example to fixtures/example.txt and fill in the expected answers",
+        dir.display()
+    );
+    Ok(())
+}
+
+/// Crate name for a day. The 2024 crates predate multiple years and are plain `dayN`.
+fn package_name(year: u16, day: u8) -> String {
+    if year == 2024 {
+        format!("day{day}")
+    } else {
+        format!("aoc{year}-day{day}")
+    }
+}
+
+/// Generates the crate for a day and registers it with the workspace and the runner.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    let dir = root.join(year.to_string()).join(format!("day{day}"));
+    ensure!(!dir.exists(), "{} already exists", dir.display());
+
+    let package = package_name(year, day);
+    let krate = package.replace('-', "_");
+    let name = format!("Day{day}");
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("fixtures"))?;
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "{package}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-core.workspace = true
+anyhow.workspace = true
+"#
+        ),
+    )?;
+    fs::write(
+        dir.join("src").join("lib.rs"),
+        lib_template(year, day, &name),
+    )?;
+    fs::write(dir.join("fixtures").join("example.txt"), "")?;
+
+    add_workspace_member(&root.join("Cargo.toml"), year)?;
+    insert_after_last(
+        &root.join("aoc").join("Cargo.toml"),
+        |line| line.contains("{ path = \"../"),
+        &format!("{package} = {{ path = \"../{year}/day{day}\" }}"),
+    )?;
+    insert_after_last(
+        &root.join("aoc").join("src").join("registry.rs"),
+        |line| line.trim_start().starts_with('&') && line.trim_end().ends_with(','),
+        &format!("    &{krate}::{name},"),
+    )?;
+    Ok(dir)
+}
+
+fn lib_template(year: u16, day: u8, name: &str) -> String {
+    format!(
+        r#"use anyhow::{{bail, Result}};
+use aoc_core::{{parse, Solution}};
+
+pub struct {name};
+
+impl Solution for {name} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Parsed = Vec<Vec<i64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    // Parses lines of numbers straight from the borrowed input; reshape it to fit the puzzle.
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i64>>> {{
+        Ok(parse::lines(input)
+            .map(|line| line.numbers())
+            .collect::<Result<_, _>>()?)
+    }}
+
+    fn part1(&self, _rows: &Vec<Vec<i64>>) -> Result<u64> {{
+        bail!("Part 1 is not solved yet")
+    }}
+
+    fn part2(&self, _rows: &Vec<Vec<i64>>) -> Result<u64> {{
+        bail!("Part 2 is not solved yet")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_example() {{
+        let parsed = {name}.parse(EXAMPLE).unwrap();
+        // TODO: replace with the answer from the puzzle's worked example.
+        assert_eq!({name}.part1(&parsed).unwrap(), 0);
+    }}
+
+    #[test]
+    fn part2_example() {{
+        let parsed = {name}.parse(EXAMPLE).unwrap();
+        // TODO: replace with the answer from the puzzle's worked example.
+        assert_eq!({name}.part2(&parsed).unwrap(), 0);
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `<year>/day*` to the workspace members unless it is already there.
+fn add_workspace_member(manifest: &Path, year: u16) -> Result<()> {
+    let contents = read(manifest)?;
+    let member = format!("\"{year}/day*\"");
+    if contents.contains(&member) {
+        return Ok(());
+    }
+    let Some(start) = contents.find("members = [") else {
+        bail!("No workspace members list in {}", manifest.display());
+    };
+    let end = start
+        + contents[start..]
+            .find(']')
+            .with_context(|| format!("Unterminated members list in {}", manifest.display()))?;
+    let updated = format!("{}, {member}{}", &contents[..end], &contents[end..]);
+    write(manifest, &updated)
+}
+
+/// Inserts `new_line` after the last line matching `is_entry`.
+fn insert_after_last(path: &Path, is_entry: impl Fn(&str) -> bool, new_line: &str) -> Result<()> {
+    let contents = read(path)?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let Some(last) = lines.iter().rposition(|line| is_entry(line)) else {
+        bail!(
+            "Could not find where to register the new day in {}",
+            path.display()
+        );
+    };
+    lines.insert(last + 1, new_line);
+    write(path, &(lines.join("\n") + "\n"))
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    fn fake_workspace(scratch: &ScratchDir) -> PathBuf {
+        let root = scratch.path().to_path_buf();
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"2024/day*\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../2024/day1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("registry.rs"),
+            "pub static SOLUTIONS: &[&dyn DynSolution] = &[\n    &day1::Day1,\n];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn generates_and_registers_a_day() {
+        let scratch = ScratchDir::new("new");
+        let root = fake_workspace(&scratch);
+
+        let dir = scaffold(&root, 2024, 9).unwrap();
+
+        let lib = fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day9"));
+        assert!(lib.contains("const DAY: u8 = 9;"));
+        assert!(dir.join("fixtures").join("example.txt").exists());
+        let manifest = fs::read_to_string(root.join("aoc").join("Cargo.toml")).unwrap();
+        assert!(manifest.ends_with("day9 = { path = \"../2024/day9\" }\n"));
+        let registry =
+            fs::read_to_string(root.join("aoc").join("src").join("registry.rs")).unwrap();
+        assert!(registry.contains("    &day1::Day1,\n    &day9::Day9,\n];"));
+        assert!(scaffold(&root, 2024, 9).is_err());
+    }
+
+    #[test]
+    fn adds_new_years_to_the_workspace() {
+        let scratch = ScratchDir::new("new");
+        let root = fake_workspace(&scratch);
+
+        scaffold(&root, 2025, 1).unwrap();
+
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("members = [\"aoc\", \"2024/day*\", \"2025/day*\"]"));
+        let registry =
+            fs::read_to_string(root.join("aoc").join("src").join("registry.rs")).unwrap();
+        assert!(registry.contains("&aoc2025_day1::Day1,"));
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::render::{self, Scene};
+use aoc_core::{DynSolution, Part};
+use clap::{Args, ValueEnum};
+
+use crate::export;
+use crate::registry;
+use crate::run::{self, InputArgs};
+
+#[derive(Args)]
+pub struct ShowArgs {
+    /// Puzzle year, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day
+    #[arg(long)]
+    day: u8,
+    /// Which part's overlays to draw (1 or 2)
+    #[arg(long, value_parser = run::parse_part, default_value = "1")]
+    part: Part,
+    /// When to colour the output
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+    /// Write the picture to this SVG file instead of printing it
+    #[arg(long)]
+    svg: Option<PathBuf>,
+    /// Write the picture to this PNG file instead of printing it
+    #[arg(long)]
+    png: Option<PathBuf>,
+    /// Draw a day's alternative rule set, such as `infinite` for 2024 day 8
+    #[arg(long)]
+    variant: Option<String>,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ColorChoice {
+    /// Colour when stdout is a terminal and `NO_COLOR` is unset
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// Parses the day's input and asks the solution to draw what `part` looked at.
+pub fn load_scene(
+    solution: &dyn DynSolution,
+    input: &InputArgs,
+    part: Part,
+    variant: Option<&str>,
+) -> Result<Scene> {
+    let (year, day) = (solution.year(), solution.day());
+    let source = input.source(year, day);
+    let input = source
+        .load()
+        .with_context(|| format!("Failed to read input for {year} day {day}"))?;
+    let text = input.as_str().map_err(|err| err.in_file(&source))?;
+    let mut parsed = solution.parse(text).map_err(|err| source.locate(err))?;
+    if let Some(name) = variant {
+        parsed = solution.variant(parsed, name)?;
+    }
+    solution
+        .scene(&parsed, part)?
+        .ok_or_else(|| anyhow!("{year} day {day} has nothing to show"))
+}
+
+pub fn show(args: ShowArgs) -> Result<()> {
+    let solution = registry::select(args.year, Some(args.day))?[0];
+    let scene = load_scene(solution, &args.input, args.part, args.variant.as_deref())?;
+    if let Some(path) = &args.svg {
+        export::write_svg(&scene, path)?;
+        println!("Wrote {}", path.display());
+    }
+    if let Some(path) = &args.png {
+        export::write_png(&scene, path)?;
+        println!("Wrote {}", path.display());
+    }
+    if args.svg.is_none() && args.png.is_none() {
+        print!("{}", render::render_terminal(&scene, args.color.enabled()));
+    }
+    Ok(())
+}
@@ -0,0 +1,369 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_core::{input, Part, Strictness};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{self, AnswerStore};
+use crate::client::{AocClient, Verdict};
+use crate::registry;
+use crate::run::{self, parse_part, InputArgs};
+
+/// Environment variable that overrides the location of the submission log.
+pub const SUBMISSIONS_ENV: &str = "AOC_SUBMISSIONS";
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Puzzle year, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day
+    #[arg(long)]
+    day: u8,
+    /// Puzzle part (1 or 2)
+    #[arg(long, value_parser = parse_part)]
+    part: Part,
+    /// Submit this answer instead of computing it
+    #[arg(long)]
+    answer: Option<String>,
+    /// Submission log, defaults to `submissions.json` at the workspace root
+    #[arg(long, env = SUBMISSIONS_ENV)]
+    log: Option<PathBuf>,
+    /// Answers file that accepted answers are recorded in
+    #[arg(long, env = answers::ANSWERS_ENV)]
+    answers: Option<PathBuf>,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rejection {
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedAnswer {
+    pub answer: String,
+    pub reason: Rejection,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PartLog {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected: Vec<RejectedAnswer>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LogData {
+    /// Unix time before which the server will refuse any submission.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retry_after: Option<u64>,
+    #[serde(default)]
+    puzzles: BTreeMap<u16, BTreeMap<u8, BTreeMap<u8, PartLog>>>,
+}
+
+/// Everything the server has told us about past submissions.
+pub struct SubmissionLog {
+    path: PathBuf,
+    data: LogData,
+}
+
+/// What happened to an answer handed to [`submit_answer`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submission {
+    /// Not sent, because the log already rules the answer out.
+    Skipped(String),
+    Judged(Verdict),
+}
+
+impl SubmissionLog {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let data = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse submission log {}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => LogData::default(),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read submission log {}", path.display()))
+            }
+        };
+        Ok(Self { path, data })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.data)? + "\n")
+            .with_context(|| format!("Failed to write submission log {}", self.path.display()))
+    }
+
+    pub fn part(&self, year: u16, day: u8, part: Part) -> Option<&PartLog> {
+        self.data.puzzles.get(&year)?.get(&day)?.get(&part.number())
+    }
+
+    fn part_mut(&mut self, year: u16, day: u8, part: Part) -> &mut PartLog {
+        self.data
+            .puzzles
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .entry(part.number())
+            .or_default()
+    }
+
+    /// Why `answer` should not be sent, if the log already knows how it would be judged.
+    pub fn refusal(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Option<String> {
+        if let Some(log) = self.part(year, day, part) {
+            if let Some(correct) = &log.correct {
+                return Some(if correct == answer {
+                    format!("{answer} was already accepted")
+                } else {
+                    format!("already solved with {correct}")
+                });
+            }
+            let value = answer.parse::<i128>().ok();
+            for rejected in &log.rejected {
+                if rejected.answer == answer {
+                    return Some(format!(
+                        "{answer} was already rejected ({:?})",
+                        rejected.reason
+                    ));
+                }
+                let (Some(value), Ok(bound)) = (value, rejected.answer.parse::<i128>()) else {
+                    continue;
+                };
+                match rejected.reason {
+                    Rejection::TooHigh if value >= bound => {
+                        return Some(format!("{answer} is not below {bound}, which was too high"))
+                    }
+                    Rejection::TooLow if value <= bound => {
+                        return Some(format!("{answer} is not above {bound}, which was too low"))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        match self.data.retry_after {
+            Some(retry_after) if retry_after > now => {
+                Some(format!("rate limited for another {}s", retry_after - now))
+            }
+            _ => None,
+        }
+    }
+
+    fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        verdict: &Verdict,
+        now: u64,
+    ) {
+        let reason = match verdict {
+            Verdict::Correct => {
+                self.part_mut(year, day, part).correct = Some(answer.to_string());
+                return;
+            }
+            Verdict::RateLimited(wait) => {
+                self.data.retry_after = Some(now + wait.as_secs());
+                return;
+            }
+            Verdict::TooHigh => Rejection::TooHigh,
+            Verdict::TooLow => Rejection::TooLow,
+            Verdict::Incorrect => Rejection::Incorrect,
+            Verdict::WrongLevel | Verdict::Unrecognised(_) => return,
+        };
+        self.part_mut(year, day, part)
+            .rejected
+            .push(RejectedAnswer {
+                answer: answer.to_string(),
+                reason,
+            });
+        // A wrong answer locks out further submissions for at least a minute.
+        self.data.retry_after = Some(now + 60);
+    }
+}
+
+/// Sends `answer` unless the log rules it out, and records the verdict.
+pub fn submit_answer(
+    client: &AocClient,
+    log: &mut SubmissionLog,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Submission> {
+    if let Some(reason) = log.refusal(year, day, part, answer, now) {
+        return Ok(Submission::Skipped(reason));
+    }
+    let verdict = client.submit(year, day, part, answer)?;
+    log.record(year, day, part, answer, &verdict, now);
+    log.save()?;
+    Ok(Submission::Judged(verdict))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+pub fn submit(args: SubmitArgs) -> Result<()> {
+    let solution = registry::select(args.year, Some(args.day))?[0];
+    let (year, day, part) = (solution.year(), solution.day(), args.part);
+
+    // Computed answers come with the input hash so an accepted one can be stored for verify.
+    let (answer, input_hash) = match args.answer {
+        Some(answer) => (answer, None),
+        None => {
+            let source = args.input.source(year, day);
+            let loaded = source.load()?;
+            let input = loaded.as_str().map_err(|err| err.in_file(&source))?;
+            let outcome = run::run_input(solution, input, &[part], Strictness::Strict, None);
+            let answer = outcome
+                .result?
+                .remove(0)
+                .answer
+                .with_context(|| format!("{year} day {day} part {part} failed"))?;
+            (answer, Some(answers::input_hash(input)))
+        }
+    };
+
+    let log_path = args
+        .log
+        .unwrap_or_else(|| input::workspace_dir().join("submissions.json"));
+    let mut log = SubmissionLog::load(log_path)?;
+    let now = unix_now();
+    // Only build the client, and so require a session, when the log lets the answer through.
+    let submission = match log.refusal(year, day, part, &answer, now) {
+        Some(reason) => Submission::Skipped(reason),
+        None => {
+            let client = AocClient::from_env()?;
+            submit_answer(&client, &mut log, year, day, part, &answer, now)?
+        }
+    };
+    let verdict = match submission {
+        Submission::Judged(verdict) => verdict,
+        Submission::Skipped(reason) => {
+            bail!("Not submitting {answer} for {year} day {day} part {part}: {reason}")
+        }
+    };
+
+    match verdict {
+        Verdict::Correct => {
+            println!("{year} day {day} part {part}: {answer} is correct");
+            if let Some(hash) = input_hash {
+                let path = args.answers.unwrap_or_else(answers::default_answers_path);
+                let mut store = AnswerStore::load(path)?;
+                store.set(year, day, &hash, part, answer);
+                store.save()?;
+            }
+            Ok(())
+        }
+        Verdict::TooHigh => Err(anyhow!("{answer} is too high")),
+        Verdict::TooLow => Err(anyhow!("{answer} is too low")),
+        Verdict::Incorrect => Err(anyhow!("{answer} is not the right answer")),
+        Verdict::RateLimited(wait) => Err(anyhow!("Submitted too recently, wait {wait:?}")),
+        Verdict::WrongLevel => Err(anyhow!(
+            "{year} day {day} part {part} is not open for answers; was it already solved?"
+        )),
+        Verdict::Unrecognised(text) => Err(anyhow!("Could not read the response: {text}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use crate::scratch::ScratchDir;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    fn empty_log(scratch: &ScratchDir) -> SubmissionLog {
+        SubmissionLog::load(scratch.join("submissions.json")).unwrap()
+    }
+
+    #[test]
+    fn posts_level_and_answer_and_remembers_success() {
+        let server = MockServer::start(vec![(200, page("That's the right answer!"))]);
+        let client = AocClient::new(server.url(), "secret");
+        let scratch = ScratchDir::new("submit");
+        let mut log = empty_log(&scratch);
+
+        let result = submit_answer(&client, &mut log, 2024, 6, Part::Two, "1523", 1_000).unwrap();
+
+        assert_eq!(result, Submission::Judged(Verdict::Correct));
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/6/answer "));
+        assert!(requests[0].ends_with("level=2&answer=1523"));
+        assert_eq!(
+            log.part(2024, 6, Part::Two).unwrap().correct.as_deref(),
+            Some("1523")
+        );
+    }
+
+    #[test]
+    fn never_resubmits_a_rejected_answer() {
+        let server = MockServer::start(vec![(
+            200,
+            page("That's not the right answer; your answer is too high."),
+        )]);
+        let client = AocClient::new(server.url(), "secret");
+        let scratch = ScratchDir::new("submit");
+        let mut log = empty_log(&scratch);
+
+        let first = submit_answer(&client, &mut log, 2024, 6, Part::Two, "2000", 1_000).unwrap();
+        let again = submit_answer(&client, &mut log, 2024, 6, Part::Two, "2000", 5_000).unwrap();
+        let higher = submit_answer(&client, &mut log, 2024, 6, Part::Two, "2500", 5_000).unwrap();
+
+        assert_eq!(first, Submission::Judged(Verdict::TooHigh));
+        assert!(matches!(again, Submission::Skipped(_)));
+        assert!(matches!(higher, Submission::Skipped(_)));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn waits_out_the_rate_limit() {
+        let server = MockServer::start(vec![(
+            200,
+            page("You gave an answer too recently.  You have 30s left to wait."),
+        )]);
+        let client = AocClient::new(server.url(), "secret");
+        let scratch = ScratchDir::new("submit");
+        let mut log = empty_log(&scratch);
+
+        let first = submit_answer(&client, &mut log, 2024, 7, Part::One, "3749", 1_000).unwrap();
+
+        assert_eq!(
+            first,
+            Submission::Judged(Verdict::RateLimited(Duration::from_secs(30)))
+        );
+        assert!(log.refusal(2024, 7, Part::One, "3749", 1_010).is_some());
+        assert!(log.refusal(2024, 7, Part::One, "3749", 1_030).is_none());
+    }
+}
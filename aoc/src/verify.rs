@@ -0,0 +1,211 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use aoc_core::input::{self, InputSource};
+use aoc_core::{DynSolution, Part, Strictness};
+use clap::{ArgGroup, Args};
+
+use crate::answers::{self, AnswerStore};
+use crate::registry;
+use crate::run::{self, InputArgs};
+
+#[derive(Args)]
+// A single input file only makes sense for a single day.
+#[command(group(ArgGroup::new("one_input").arg("input").requires("day")))]
+pub struct VerifyArgs {
+    /// Only verify this year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only verify this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// Store answers for inputs that have none recorded yet
+    #[arg(long)]
+    record: bool,
+    /// Answers file, defaults to `answers.json` at the workspace root
+    #[arg(long, env = answers::ANSWERS_ENV)]
+    answers: Option<PathBuf>,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    recorded: usize,
+}
+
+pub fn verify(args: VerifyArgs) -> Result<()> {
+    let path = args
+        .answers
+        .clone()
+        .unwrap_or_else(answers::default_answers_path);
+    let mut store = AnswerStore::load(path)?;
+    let mut tally = Tally::default();
+
+    for solution in registry::select(args.year, args.day)? {
+        let source = args.input.source(solution.year(), solution.day());
+        verify_day(solution, &source, &mut store, args.record, &mut tally)?;
+    }
+
+    if tally.recorded > 0 {
+        store.save()?;
+        println!("Recorded answers to {}", store.path().display());
+    }
+    println!(
+        "{} passed, {} failed, {} missing, {} recorded",
+        tally.passed, tally.failed, tally.missing, tally.recorded
+    );
+    if tally.failed > 0 {
+        bail!("{} answer(s) did not match", tally.failed);
+    }
+    Ok(())
+}
+
+/// Checks one day against the store; an input that exists but can't be read fails the run.
+fn verify_day(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    store: &mut AnswerStore,
+    record: bool,
+    tally: &mut Tally,
+) -> Result<()> {
+    let (year, day) = (solution.year(), solution.day());
+    let loaded = match source.load() {
+        Ok(loaded) => loaded,
+        Err(err) if input::is_missing(&err) => {
+            println!("{year} day {day}: missing input ({source})");
+            tally.missing += Part::ALL.len();
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    };
+    let input = match loaded.as_str() {
+        Ok(input) => input,
+        Err(err) => {
+            println!("{year} day {day}: FAIL could not parse input: {err}");
+            tally.failed += Part::ALL.len();
+            return Ok(());
+        }
+    };
+    let hash = answers::input_hash(input);
+
+    let outcome = run::run_input(solution, input, &Part::ALL, Strictness::Strict, None);
+    let parts = match outcome.result {
+        Ok(parts) => parts,
+        Err(err) => {
+            println!("{year} day {day}: FAIL could not parse input: {err:#}");
+            tally.failed += Part::ALL.len();
+            return Ok(());
+        }
+    };
+
+    for part in parts {
+        let label = format!("{year} day {day} part {}", part.part);
+        let expected = store.get(year, day, &hash, part.part);
+        match (part.answer, expected) {
+            (Err(err), _) => {
+                println!("{label}: FAIL {err:#}");
+                tally.failed += 1;
+            }
+            (Ok(actual), Some(expected)) if actual == expected => {
+                println!("{label}: pass ({actual})");
+                tally.passed += 1;
+            }
+            (Ok(actual), Some(expected)) => {
+                println!("{label}: FAIL expected {expected}, got {actual}");
+                tally.failed += 1;
+            }
+            (Ok(actual), None) if record => {
+                println!("{label}: recorded ({actual})");
+                store.set(year, day, &hash, part.part, actual);
+                tally.recorded += 1;
+            }
+            (Ok(actual), None) => {
+                println!("{label}: missing answer (got {actual})");
+                tally.missing += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn example_input(scratch: &ScratchDir) -> InputSource {
+        let path = scratch.join("01.txt");
+        fs::write(&path, EXAMPLE).unwrap();
+        InputSource::File(path)
+    }
+
+    fn empty_store(scratch: &ScratchDir) -> AnswerStore {
+        AnswerStore::load(scratch.join("answers.json")).unwrap()
+    }
+
+    fn check(source: &InputSource, store: &mut AnswerStore, record: bool) -> Result<Tally> {
+        let day1 = registry::select(Some(2024), Some(1))?[0];
+        let mut tally = Tally::default();
+        verify_day(day1, source, store, record, &mut tally)?;
+        Ok(tally)
+    }
+
+    #[test]
+    fn counts_passes_and_failures_against_stored_answers() {
+        let scratch = ScratchDir::new("verify");
+        let source = example_input(&scratch);
+        let mut store = empty_store(&scratch);
+        let hash = answers::input_hash(EXAMPLE);
+        store.set(2024, 1, &hash, Part::One, "11".into());
+        store.set(2024, 1, &hash, Part::Two, "32".into());
+        store.save().unwrap();
+        let mut store = AnswerStore::load(store.path()).unwrap();
+
+        let tally = check(&source, &mut store, false).unwrap();
+
+        assert_eq!(
+            tally,
+            Tally {
+                passed: 1,
+                failed: 1,
+                ..Tally::default()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_answers_are_missing_until_recorded() {
+        let scratch = ScratchDir::new("verify");
+        let source = example_input(&scratch);
+        let mut store = empty_store(&scratch);
+
+        let before = check(&source, &mut store, false).unwrap();
+        let recorded = check(&source, &mut store, true).unwrap();
+        let after = check(&source, &mut store, false).unwrap();
+
+        assert_eq!(before.missing, 2);
+        assert_eq!(recorded.recorded, 2);
+        assert_eq!(after.passed, 2);
+    }
+
+    #[test]
+    fn only_an_absent_input_counts_as_missing() {
+        let scratch = ScratchDir::new("verify");
+        let mut store = empty_store(&scratch);
+        let absent = InputSource::File(scratch.join("01.txt"));
+        let unreadable = InputSource::File(scratch.path().to_path_buf());
+
+        let tally = check(&absent, &mut store, false).unwrap();
+
+        assert_eq!(tally.missing, 2);
+        assert!(check(&unreadable, &mut store, false).is_err());
+    }
+}